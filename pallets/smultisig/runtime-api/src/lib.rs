@@ -0,0 +1,44 @@
+//! Runtime API of the multisig group pallet.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+pub use pallet_smultisig::{DryRunResult, GroupId, GroupInfo, ProposalThreshold, ProposalType};
+use sp_runtime::DispatchError;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	/// A runtime can have several instances of the pallet, each call selects one by `instance`,
+	/// the index of the instance in the runtime.
+	pub trait SmultisigApi<AccountId, Balance, BlockNumber, Event>
+	where
+		AccountId: Codec,
+		Balance: Codec,
+		BlockNumber: Codec,
+		Event: Codec,
+	{
+		/// Simulate the call of a pending proposal dispatched by the group account, nothing is
+		/// committed. The changed balances of the group account and `accounts` are reported.
+		fn dry_run_proposal(
+			instance: u8,
+			proposal_id: u32,
+			accounts: Vec<AccountId>,
+		) -> Result<DryRunResult<AccountId, Balance, Event>, DispatchError>;
+
+		/// The threshold a proposal of `proposaltype` is created with in the group, `None` for
+		/// an unknown instance.
+		fn proposal_threshold(
+			instance: u8,
+			group_id: GroupId,
+			proposaltype: ProposalType,
+		) -> Option<ProposalThreshold>;
+
+		/// The name, creator and creation block of the group.
+		fn group_info(
+			instance: u8,
+			group_id: GroupId,
+		) -> Option<GroupInfo<AccountId, BlockNumber, Vec<u8>>>;
+
+		/// The groups `who` is a member of.
+		fn groups_of(instance: u8, who: AccountId) -> Vec<GroupId>;
+	}
+}
@@ -5,25 +5,25 @@ use super::*;
 
 #[allow(unused)]
 use crate::{Pallet as Smultisig};
-use frame_benchmarking::v1::{benchmarks,account,impl_benchmark_test_suite};
+use frame_benchmarking::v1::{benchmarks_instance_pallet,account,impl_benchmark_test_suite};
 use frame_support::dispatch::RawOrigin::Root;
 use sp_std::vec;
-fn create_user<T: Config>(string: &'static str, n: u32, seed: u32) -> T::AccountId {
+fn create_user<T: Config<I>, I: 'static>(string: &'static str, n: u32, seed: u32) -> T::AccountId {
     let user = account(string, n, seed);
     user
 }
 
-benchmarks!{
+benchmarks_instance_pallet!{
     create_multisig_group{
-        let caller = create_user::<T>("caller",0u32,1u32);
-        let member_one = create_user::<T>("member_one",0u32,1u32);
-        let member_two = create_user::<T>("member_two",0u32,1u32);
+        let caller = create_user::<T, I>("caller",0u32,1u32);
+        let member_one = create_user::<T, I>("member_one",0u32,1u32);
+        let member_two = create_user::<T, I>("member_two",0u32,1u32);
         let root = Root.as_signed().unwrap();
         let members = vec![root,&caller,&member_one,&member_two];
-    }:_(Root,members)
+    }:_(Root,members,b"council".to_vec())
     verify{
-        assert_eq!(Smultisig::<T>::members().contains(&member_two),true);
-        assert_eq!(Smultisig::<T>::members().contains(&member_one),true);
+        assert_eq!(Smultisig::<T, I>::members(0).contains(&member_two),true);
+        assert_eq!(Smultisig::<T, I>::members(0).contains(&member_one),true);
     }
 }
 impl_benchmark_test_suite!(Smultisig, crate::mock::new_test_ext(), crate::mock::Test);
\ No newline at end of file
@@ -8,135 +8,889 @@ mod mock;
 #[cfg(test)]
 mod test;
 pub mod weights;
+pub mod migrations;
 mod benchmarking;
 
 pub use weights::*;
 
-use frame_support::pallet_prelude::*;
+use frame_support::{
+	dispatch::{extract_actual_weight, GetDispatchInfo, PostDispatchInfo},
+	pallet_prelude::*,
+	storage::{with_storage_layer, with_transaction, TransactionOutcome},
+	traits::{
+		fungible::{InspectFreeze, MutateFreeze},
+		Bounded, ChangeMembers, Contains, Currency, ExistenceRequirement, Imbalance,
+		InitializeMembers, QueryPreimage, ReservableCurrency, SortedMembers, StorePreimage,
+	},
+	PalletId,
+};
 use frame_system::pallet_prelude::*;
-use sp_io::hashing::blake2_256;
-use sp_runtime::traits::TrailingZeroInput;
+use sp_core::H160;
+use sp_io::hashing::{blake2_256, keccak_256};
+use sp_runtime::{
+	traits::{
+		AccountIdConversion, Dispatchable, Hash as HashT, One, Saturating, TrailingZeroInput, Zero,
+	},
+	Percent, SaturatedConversion,
+};
 use sp_std::prelude::*;
 
 pub type ProposalIndex = u32;
 pub type Threshold = u32;
+pub type GroupId = u32;
+
+pub type BalanceOf<T, I = ()> =
+	<<T as Config<I>>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+pub type BoundedCallOf<T, I = ()> = Bounded<<T as Config<I>>::RuntimeCall>;
+
+pub type RevisionOf<T, I = ()> = ProposalRevision<
+	<T as frame_system::Config>::AccountId,
+	BoundedCallOf<T, I>,
+	BlockNumberFor<T>,
+>;
+
+pub type GroupInfoOf<T, I = ()> = GroupInfo<
+	<T as frame_system::Config>::AccountId,
+	BlockNumberFor<T>,
+	BoundedVec<u8, <T as Config<I>>::MaxGroupNameLength>,
+>;
+
+pub type DryRunResultOf<T, I = ()> = DryRunResult<
+	<T as frame_system::Config>::AccountId,
+	BalanceOf<T, I>,
+	<T as frame_system::Config>::RuntimeEvent,
+>;
 
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
 	use core::marker::PhantomData;
 
+	/// The storage version, version 1 keys the storage by group.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::without_storage_info]
-	pub struct Pallet<T>(PhantomData<T>);
+	pub struct Pallet<T, I = ()>(PhantomData<(T, I)>);
 
 	#[pallet::config]
-	pub trait Config: frame_system::Config {
-		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+	pub trait Config<I: 'static = ()>: frame_system::Config {
+		type RuntimeEvent: From<Event<Self, I>>
+			+ IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
 		type WeightInfo: WeightInfo;
 
+		/// The call type a proposal is allowed to dispatch as the group account.
+		type RuntimeCall: Parameter
+			+ Dispatchable<RuntimeOrigin = Self::RuntimeOrigin, PostInfo = PostDispatchInfo>
+			+ GetDispatchInfo
+			+ From<frame_system::Call<Self>>;
+
+		/// Currency used to reserve the proposal deposit.
+		type Currency: ReservableCurrency<Self::AccountId>;
+
+		/// Where the call of a proposal is noted, the proposal only keeps its hash.
+		type Preimages: QueryPreimage + StorePreimage;
+
+		/// Notified whenever the members of the primary group change.
+		type MembershipChanged: ChangeMembers<Self::AccountId>;
+
+		/// Used to derive the accounts of the multisig groups.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		#[pallet::constant]
+		type MaxTitleLength: Get<u32>; // 64
+
+		/// The longest name of a group.
+		#[pallet::constant]
+		type MaxGroupNameLength: Get<u32>;
+
+		/// Deposit reserved from the proposer of a call proposal.
+		#[pallet::constant]
+		type ProposalDepositBase: Get<BalanceOf<Self, I>>;
+
+		/// Deposit reserved for each byte of the noted call.
+		#[pallet::constant]
+		type ProposalDepositPerByte: Get<BalanceOf<Self, I>>;
+
 		#[pallet::constant]
 		type MaxMultisigNumber: Get<u32>; //5
 
+		/// How many proposals of the group can be pending at once.
 		#[pallet::constant]
 		type MaxProposalNumber: Get<u32>; // 15
 
+		/// How many proposals of a member can be pending at once.
+		#[pallet::constant]
+		type MaxMemberProposalNumber: Get<u32>;
+
+		/// The group can not shrink below this number of members.
+		#[pallet::constant]
+		type MinMultisigNumber: Get<u32>;
+
+		/// How long a proposal can be voted on before it expires.
+		#[pallet::constant]
+		type VotingPeriod: Get<BlockNumberFor<Self>>;
+
+		/// How long the members can reveal their votes after the commit phase of a
+		/// `CommitReveal` proposal, the commit phase lasts `VotingPeriod`.
+		#[pallet::constant]
+		type RevealPeriod: Get<BlockNumberFor<Self>>;
+
+		/// How many decided proposals in a row a member can miss before the pallet proposes
+		/// its removal.
+		#[pallet::constant]
+		type MaxMissedProposals: Get<u32>;
+
+		/// How long a member can go without voting before the pallet proposes its removal.
+		#[pallet::constant]
+		type InactivityPeriod: Get<BlockNumberFor<Self>>;
+
+		/// The default threshold of a `FreezeGroup` proposal, lower than the threshold of the
+		/// group so the members can react fast on a leaked key.
 		#[pallet::constant]
-		type MinMultisigNumber: Get<u32>; // 2
+		type FreezeThreshold: Get<ProposalThreshold>;
+
+		/// How many finished proposals are kept, the oldest are pruned in `on_idle`.
+		#[pallet::constant]
+		type MaxFinishedProposals: Get<u32>;
+
+		/// The priority of the unsigned votes of the Ethereum keys.
+		#[pallet::constant]
+		type EthVotePriority: Get<TransactionPriority>;
+
+		/// Freezes the balance the members vote with in a `TokenWeighted` group.
+		type Freezer: MutateFreeze<Self::AccountId, Balance = BalanceOf<Self, I>>;
+
+		/// The freeze of the votes of the instance, one freeze covers all of the votes of a
+		/// member.
+		type FreezeId: Get<<Self::Freezer as InspectFreeze<Self::AccountId>>::Id>;
+
+		/// Bond reserved from the submitter of a petition until the petition is finished.
+		#[pallet::constant]
+		type PetitionBond: Get<BalanceOf<Self, I>>;
+
+		/// The part of the bond of a dismissed or lapsed petition which is paid to the group
+		/// account.
+		#[pallet::constant]
+		type PetitionForfeit: Get<Percent>;
+
+		/// Deposit reserved from the creator of a group until the group is dissolved.
+		#[pallet::constant]
+		type GroupDeposit: Get<BalanceOf<Self, I>>;
+
+		/// The maximum number of groups which exist at the same time.
+		#[pallet::constant]
+		type MaxGroupNumber: Get<u32>;
 	}
 
 	#[pallet::storage]
 	#[pallet::getter(fn members)]
-	pub type MultisigMembers<T: Config> =
-		StorageValue<_, BoundedVec<T::AccountId, T::MaxMultisigNumber>, ValueQuery>;
+	pub type MultisigMembers<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		GroupId,
+		BoundedVec<T::AccountId, T::MaxMultisigNumber>,
+		ValueQuery,
+	>;
+
+	// the number of groups created, ids are never reused
+	#[pallet::storage]
+	#[pallet::getter(fn group_count)]
+	pub type GroupCount<T: Config<I>, I: 'static = ()> = StorageValue<_, GroupId, ValueQuery>;
+
+	// the group created for the members managed through `InitializeMembers` and `ChangeMembers`
+	#[pallet::storage]
+	#[pallet::getter(fn primary_group)]
+	pub type PrimaryGroup<T: Config<I>, I: 'static = ()> = StorageValue<_, GroupId, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn groups)]
+	pub type Groups<T: Config<I>, I: 'static = ()> =
+		CountedStorageMap<_, Twox64Concat, GroupId, GroupInfoOf<T, I>>;
+
+	// deposit reserved by the creator of a group until the group is dissolved
+	#[pallet::storage]
+	#[pallet::getter(fn group_deposit)]
+	pub type GroupDeposits<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, GroupId, (T::AccountId, BalanceOf<T, I>)>;
+
+	// the groups of an account, in the order it joined them
+	#[pallet::storage]
+	#[pallet::getter(fn groups_of)]
+	pub type MemberGroups<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, T::AccountId, Vec<GroupId>, ValueQuery>;
 
 	#[pallet::storage]
 	#[pallet::getter(fn proposals)]
-	pub type Proposals<T: Config> = StorageMap<_, Twox64Concat, u32, Proposal<T>>;
+	pub type Proposals<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, u32, Proposal<T, I>>;
+
+	// the last proposal id, ids are never reused so finished proposals keep their id
+	#[pallet::storage]
+	#[pallet::getter(fn proposal_count)]
+	pub type ProposalCount<T: Config<I>, I: 'static = ()> = StorageValue<_, u32, ValueQuery>;
 
 	#[pallet::storage]
 	#[pallet::getter(fn finished_proposal)]
-	pub type FinishedProposal<T: Config> = StorageMap<_, Twox64Concat, u32, Proposal<T>>;
+	pub type FinishedProposal<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, u32, ProposalRecord<T, I>>;
+
+	// finished proposal ids, oldest first
+	#[pallet::storage]
+	#[pallet::getter(fn finished_queue)]
+	pub type FinishedQueue<T: Config<I>, I: 'static = ()> = StorageValue<_, Vec<u32>, ValueQuery>;
 
 	#[pallet::storage]
 	#[pallet::getter(fn votings)]
-	pub type Voting<T: Config> = StorageMap<_, Identity, u32, Votes<T>, OptionQuery>;
+	pub type Voting<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Identity, u32, Votes<T>, OptionQuery>;
+
+	// the block the group was frozen at, nothing but unfreezing can go on while it is set
+	#[pallet::storage]
+	#[pallet::getter(fn frozen)]
+	pub type Frozen<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, GroupId, BlockNumberFor<T>, OptionQuery>;
+
+	// the turnout and approval ratio deciding the proposals at the end of their voting period
+	#[pallet::storage]
+	#[pallet::getter(fn quorum)]
+	pub type Quorum<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, GroupId, QuorumConfig, OptionQuery>;
+
+	// delegator -> (delegate, last block the delegate can vote for the delegator)
+	#[pallet::storage]
+	#[pallet::getter(fn delegations)]
+	pub type Delegations<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		GroupId,
+		Twox64Concat,
+		T::AccountId,
+		(T::AccountId, BlockNumberFor<T>),
+	>;
+
+	// salted vote hashes of a commit-reveal proposal, removed once revealed
+	#[pallet::storage]
+	#[pallet::getter(fn commitments)]
+	pub type Commitments<T: Config<I>, I: 'static = ()> =
+		StorageDoubleMap<_, Twox64Concat, u32, Twox64Concat, T::AccountId, T::Hash>;
 
 	// add member
 	#[pallet::storage]
 	#[pallet::getter(fn add_members)]
-	pub type AddMember<T: Config> = StorageMap<_, Twox64Concat, u32, T::AccountId>;
+	pub type AddMember<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, u32, T::AccountId>;
 
 	// remove member
 	#[pallet::storage]
 	#[pallet::getter(fn remove_members)]
-	pub type RemoveMember<T: Config> = StorageMap<_, Twox64Concat, u32, T::AccountId>;
+	pub type RemoveMember<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, u32, T::AccountId>;
+
+	// swap member, the replaced member and its replacement
+	#[pallet::storage]
+	#[pallet::getter(fn swap_members)]
+	pub type SwapMember<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, u32, (T::AccountId, T::AccountId)>;
+
+	// call of a proposal, refer to the noted preimage
+	#[pallet::storage]
+	#[pallet::getter(fn call_proposals)]
+	pub type CallProposal<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, u32, BoundedCallOf<T, I>>;
+
+	// the dispatch weight of the call of a proposal, recorded when the call is noted
+	#[pallet::storage]
+	#[pallet::getter(fn call_weight)]
+	pub type CallWeights<T: Config<I>, I: 'static = ()> = StorageMap<_, Twox64Concat, u32, Weight>;
+
+	// deposit reserved by the proposer until the proposal is finished
+	#[pallet::storage]
+	#[pallet::getter(fn deposit_of)]
+	pub type ProposalDeposit<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, u32, (T::AccountId, BalanceOf<T, I>)>;
+
+	// prerequisite proposals which have not been executed yet
+	#[pallet::storage]
+	#[pallet::getter(fn prerequisites)]
+	pub type Prerequisites<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, u32, Vec<u32>, ValueQuery>;
+
+	// the accounts sharing the funds of the group account once it is dissolved
+	#[pallet::storage]
+	#[pallet::getter(fn dissolve_beneficiaries)]
+	pub type DissolveBeneficiaries<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, u32, BoundedVec<T::AccountId, T::MaxMultisigNumber>>;
+
+	// the threshold of each proposal type set by the group, see `threshold_of` for the defaults
+	#[pallet::storage]
+	#[pallet::getter(fn thresholds)]
+	pub type Thresholds<T: Config<I>, I: 'static = ()> =
+		StorageDoubleMap<_, Twox64Concat, GroupId, Twox64Concat, ProposalType, ProposalThreshold>;
+
+	// co-sponsors a proposal needs before it is opened for voting, proposals start as drafts
+	// when it is set
+	#[pallet::storage]
+	#[pallet::getter(fn required_sponsors)]
+	pub type RequiredSponsors<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, GroupId, u32, ValueQuery>;
+
+	// the members who sponsored a draft proposal
+	#[pallet::storage]
+	#[pallet::getter(fn sponsors)]
+	pub type Sponsors<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, u32, Vec<T::AccountId>, ValueQuery>;
+
+	// the replaced revisions of a proposal, oldest first
+	#[pallet::storage]
+	#[pallet::getter(fn revisions)]
+	pub type Revisions<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, u32, Vec<RevisionOf<T, I>>, ValueQuery>;
+
+	// the block a member voted at last, or joined the group at
+	#[pallet::storage]
+	#[pallet::getter(fn last_active)]
+	pub type LastActive<T: Config<I>, I: 'static = ()> =
+		StorageDoubleMap<_, Twox64Concat, GroupId, Twox64Concat, T::AccountId, BlockNumberFor<T>>;
+
+	// the number of decided proposals in a row a member did not vote on
+	#[pallet::storage]
+	#[pallet::getter(fn missed_proposals)]
+	pub type MissedProposals<T: Config<I>, I: 'static = ()> =
+		StorageDoubleMap<_, Twox64Concat, GroupId, Twox64Concat, T::AccountId, u32, ValueQuery>;
+
+	// the number of pending proposals of a member
+	#[pallet::storage]
+	#[pallet::getter(fn member_proposals)]
+	pub type MemberProposals<T: Config<I>, I: 'static = ()> =
+		StorageDoubleMap<_, Twox64Concat, GroupId, Twox64Concat, T::AccountId, u32, ValueQuery>;
+
+	// the number of pending proposals of a group
+	#[pallet::storage]
+	#[pallet::getter(fn group_proposals)]
+	pub type GroupProposals<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, GroupId, u32, ValueQuery>;
 
+	// the number of pending proposals removing a member
+	#[pallet::storage]
+	#[pallet::getter(fn pending_removals)]
+	pub type PendingRemovals<T: Config<I>, I: 'static = ()> =
+		StorageDoubleMap<_, Twox64Concat, GroupId, Twox64Concat, T::AccountId, u32, ValueQuery>;
+
+	// the last group checked for inactive members, the next check starts after it
+	#[pallet::storage]
+	#[pallet::getter(fn inactivity_cursor)]
+	pub type InactivityCursor<T: Config<I>, I: 'static = ()> = StorageValue<_, GroupId>;
+
+	// proposals waiting for the execution of a prerequisite
+	#[pallet::storage]
+	#[pallet::getter(fn dependents)]
+	pub type Dependents<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, u32, Vec<u32>, ValueQuery>;
+
+	// how the votes of the group are counted
+	#[pallet::storage]
+	#[pallet::getter(fn tally_mode)]
+	pub type TallyModes<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, GroupId, TallyMode, ValueQuery>;
+
+	// the votes on a `TokenWeighted` proposal, as (approve, frozen balance)
+	#[pallet::storage]
+	#[pallet::getter(fn weighted_votes)]
+	pub type WeightedVotes<T: Config<I>, I: 'static = ()> =
+		StorageDoubleMap<_, Twox64Concat, u32, Twox64Concat, T::AccountId, (bool, BalanceOf<T, I>)>;
+
+	// the groups approving a joint proposal with the threshold of each, the first one proposed it
+	#[pallet::storage]
+	#[pallet::getter(fn joint_groups)]
+	pub type JointGroups<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, u32, Vec<(GroupId, ProposalThreshold)>>;
+
+	// the calls a group may dispatch, any call while the group has no filter
+	#[pallet::storage]
+	#[pallet::getter(fn call_filter)]
+	pub type CallFilters<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, GroupId, Vec<AllowedCall>>;
+
+	// the filter set by a `SetCallFilter` proposal, `None` lifts the filter of the group
+	#[pallet::storage]
+	#[pallet::getter(fn proposed_call_filter)]
+	pub type ProposedCallFilters<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, u32, Option<Vec<AllowedCall>>>;
+
+	// bond reserved by the submitter of a petition until the petition is finished
+	#[pallet::storage]
+	#[pallet::getter(fn petition_bond)]
+	pub type PetitionBonds<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, u32, (T::AccountId, BalanceOf<T, I>)>;
+
+	/// Every event is deposited with topics, see `Pallet::deposit_event`.
 	#[pallet::event]
-	#[pallet::generate_deposit(pub (super) fn deposit_event)]
-	pub enum Event<T: Config> {
+	pub enum Event<T: Config<I>, I: 'static = ()> {
 		CreateMultisig {
+			group_id: GroupId,
 			who: T::AccountId,
 			dyn_threshold: u32,
 		},
 		CreateProposal {
-			who: T::AccountId,
+			group_id: GroupId,
 			proposal_id: u32,
+			proposal_hash: T::Hash,
+			proposaltype: ProposalType,
+			who: T::AccountId,
 			threshold: ProposalThreshold,
 			status: ProposalStatus,
 		},
 		ApprovalProposal {
+			group_id: GroupId,
 			proposal_id: u32,
 			vote: u32,
 			who: T::AccountId,
 		},
 
-		FinshedProposal {
+		FinishedProposal {
+			group_id: GroupId,
 			proposal_id: u32,
 			vote: u32,
+			outcome: ProposalOutcome,
 		},
 
 		RejectProposal {
+			group_id: GroupId,
 			proposal_id: u32,
 			vote: u32,
 			who: T::AccountId,
 		},
-		// add / remove members
+		// add / remove members, `account` owned the proposal and `dynthreshold` is the dynamic
+		// threshold of the changed group
 		ChangeGroup {
+			group_id: GroupId,
 			account: T::AccountId,
+			incoming: Vec<T::AccountId>,
+			outgoing: Vec<T::AccountId>,
 			dynthreshold: u32,
 		},
+		// the call of a proposal was dispatched by the group account
+		CallExecuted {
+			group_id: GroupId,
+			proposal_id: u32,
+			result: DispatchResult,
+		},
+		// oldest finished proposals removed from the history
+		PrunedProposals {
+			count: u32,
+		},
+		// a member committed the hash of a secret vote
+		VoteCommitted {
+			group_id: GroupId,
+			proposal_id: u32,
+			who: T::AccountId,
+		},
+		// a member revealed a secret vote
+		VoteRevealed {
+			group_id: GroupId,
+			proposal_id: u32,
+			who: T::AccountId,
+			approve: bool,
+		},
+		// a member let another member vote on its behalf
+		VoteDelegated {
+			group_id: GroupId,
+			who: T::AccountId,
+			to: T::AccountId,
+			until: BlockNumberFor<T>,
+		},
+		DelegationRemoved {
+			group_id: GroupId,
+			who: T::AccountId,
+		},
+		// a delegate voted on behalf of a member
+		DelegatedVote {
+			group_id: GroupId,
+			proposal_id: u32,
+			delegator: T::AccountId,
+			delegate: T::AccountId,
+			approve: bool,
+		},
+		GroupFrozen {
+			group_id: GroupId,
+			proposal_id: u32,
+		},
+		GroupUnfrozen {
+			group_id: GroupId,
+			proposal_id: u32,
+		},
+		// a member was replaced in place
+		MemberSwapped {
+			group_id: GroupId,
+			proposal_id: u32,
+			old: T::AccountId,
+			new: T::AccountId,
+		},
+		QuorumSet {
+			group_id: GroupId,
+			quorum: Option<QuorumConfig>,
+		},
+		RequiredSponsorsSet {
+			group_id: GroupId,
+			count: u32,
+		},
+		// the proposals of the type are created with the threshold from now on
+		ThresholdSet {
+			group_id: GroupId,
+			proposaltype: ProposalType,
+			threshold: ProposalThreshold,
+		},
+		ProposalSponsored {
+			group_id: GroupId,
+			proposal_id: u32,
+			who: T::AccountId,
+		},
+		// the draft has enough sponsors, its voting period ends at `end`
+		ProposalOpened {
+			group_id: GroupId,
+			proposal_id: u32,
+			end: BlockNumberFor<T>,
+		},
+		// the owner amended the payload of the proposal, its votes were reset
+		ProposalAmended {
+			group_id: GroupId,
+			proposal_id: u32,
+			revision: u32,
+		},
+		// the group was cleared, `funds` of the group account were shared by the beneficiaries
+		GroupDissolved {
+			group_id: GroupId,
+			proposal_id: u32,
+			funds: BalanceOf<T, I>,
+		},
+		// the pallet proposed the removal of a member who stopped voting
+		InactiveMember {
+			group_id: GroupId,
+			member: T::AccountId,
+			proposal_id: u32,
+		},
+		// the proposal was approved and waits for its prerequisites to be executed
+		AwaitingDependencies {
+			group_id: GroupId,
+			proposal_id: u32,
+			prerequisites: Vec<u32>,
+		},
+		// a joint proposal was created, its call is dispatched by `account`
+		JointProposalCreated {
+			proposal_id: u32,
+			groups: Vec<(GroupId, ProposalThreshold)>,
+			account: T::AccountId,
+		},
+		// the votes of each group on a joint proposal after a vote
+		JointVoteTallied {
+			proposal_id: u32,
+			tallies: Vec<GroupTally>,
+		},
+		// the votes of the group are counted with `mode` from its next proposal on
+		TallyModeSet {
+			group_id: GroupId,
+			mode: TallyMode,
+		},
+		// a member voted with `amount` of frozen balance, with the weighted tally of the proposal
+		WeightedVoteCast {
+			group_id: GroupId,
+			proposal_id: u32,
+			who: T::AccountId,
+			approve: bool,
+			amount: BalanceOf<T, I>,
+			ayes: BalanceOf<T, I>,
+			nays: BalanceOf<T, I>,
+			threshold: BalanceOf<T, I>,
+		},
+		// an Ethereum key voted for its member account `who`
+		EthVoteCast {
+			group_id: GroupId,
+			proposal_id: u32,
+			signer: H160,
+			who: T::AccountId,
+			approve: bool,
+		},
+		// a member opened the voting on a petition, `who` voted first
+		PetitionAdopted {
+			group_id: GroupId,
+			proposal_id: u32,
+			who: T::AccountId,
+		},
+		// a member dismissed a petition, `forfeited` of the bond was paid to the group account
+		PetitionDismissed {
+			group_id: GroupId,
+			proposal_id: u32,
+			who: T::AccountId,
+			forfeited: BalanceOf<T, I>,
+		},
+		// no member adopted the petition in time, `forfeited` of the bond was paid to the group
+		// account
+		PetitionLapsed {
+			group_id: GroupId,
+			proposal_id: u32,
+			forfeited: BalanceOf<T, I>,
+		},
+		// the group may only dispatch the calls of `filter` from now on, any call if `None`
+		CallFilterSet {
+			group_id: GroupId,
+			proposal_id: u32,
+			filter: Option<Vec<AllowedCall>>,
+		},
+	}
+
+	impl<T: Config<I>, I: 'static> Event<T, I> {
+		// the topics of the group accounts, the proposal and the members of the event
+		fn topics(&self) -> Vec<T::Hash> {
+			let (groups, proposal_id, accounts) = match self {
+				Event::CreateMultisig { group_id, who, .. } |
+				Event::VoteDelegated { group_id, who, .. } |
+				Event::DelegationRemoved { group_id, who } => (vec![*group_id], None, vec![who]),
+				Event::CreateProposal { group_id, proposal_id, who, .. } |
+				Event::ApprovalProposal { group_id, proposal_id, who, .. } |
+				Event::RejectProposal { group_id, proposal_id, who, .. } |
+				Event::VoteCommitted { group_id, proposal_id, who } |
+				Event::VoteRevealed { group_id, proposal_id, who, .. } |
+				Event::ProposalSponsored { group_id, proposal_id, who } |
+				Event::InactiveMember { group_id, proposal_id, member: who } |
+				Event::EthVoteCast { group_id, proposal_id, who, .. } |
+				Event::WeightedVoteCast { group_id, proposal_id, who, .. } |
+				Event::PetitionAdopted { group_id, proposal_id, who } |
+				Event::PetitionDismissed { group_id, proposal_id, who, .. } =>
+					(vec![*group_id], Some(*proposal_id), vec![who]),
+				Event::DelegatedVote { group_id, proposal_id, delegator, delegate, .. } =>
+					(vec![*group_id], Some(*proposal_id), vec![delegator, delegate]),
+				Event::MemberSwapped { group_id, proposal_id, old, new } =>
+					(vec![*group_id], Some(*proposal_id), vec![old, new]),
+				Event::ChangeGroup { group_id, account, incoming, outgoing, .. } => (
+					vec![*group_id],
+					None,
+					sp_std::iter::once(account).chain(incoming).chain(outgoing).collect(),
+				),
+				Event::FinishedProposal { group_id, proposal_id, .. } |
+				Event::CallExecuted { group_id, proposal_id, .. } |
+				Event::GroupFrozen { group_id, proposal_id } |
+				Event::GroupUnfrozen { group_id, proposal_id } |
+				Event::ProposalOpened { group_id, proposal_id, .. } |
+				Event::ProposalAmended { group_id, proposal_id, .. } |
+				Event::GroupDissolved { group_id, proposal_id, .. } |
+				Event::AwaitingDependencies { group_id, proposal_id, .. } |
+				Event::CallFilterSet { group_id, proposal_id, .. } |
+				Event::PetitionLapsed { group_id, proposal_id, .. } =>
+					(vec![*group_id], Some(*proposal_id), Vec::new()),
+				Event::QuorumSet { group_id, .. } |
+				Event::RequiredSponsorsSet { group_id, .. } |
+				Event::ThresholdSet { group_id, .. } |
+				Event::TallyModeSet { group_id, .. } => (vec![*group_id], None, Vec::new()),
+				Event::JointProposalCreated { proposal_id, groups, account } => (
+					groups.iter().map(|(group_id, _)| *group_id).collect(),
+					Some(*proposal_id),
+					vec![account],
+				),
+				Event::JointVoteTallied { proposal_id, tallies } => (
+					tallies.iter().map(|tally| tally.group_id).collect(),
+					Some(*proposal_id),
+					Vec::new(),
+				),
+				Event::PrunedProposals { .. } | Event::__Ignore(..) =>
+					(Vec::new(), None, Vec::new()),
+			};
+
+			groups
+				.into_iter()
+				.map(|group_id| {
+					Pallet::<T, I>::account_topic(&Pallet::<T, I>::account_id(group_id))
+				})
+				.chain(proposal_id.map(Pallet::<T, I>::proposal_hash))
+				.chain(accounts.into_iter().map(Pallet::<T, I>::account_topic))
+				.collect()
+		}
 	}
 
 	#[derive(PartialEq, Eq, Debug, Clone, Copy, Encode, Decode, TypeInfo, MaxEncodedLen)]
 	pub enum ProposalStatus {
 		Pending,
 		Finished,
+		/// Approved, executed once all of its prerequisites are executed.
+		AwaitingDependencies,
+		/// Waiting for `RequiredSponsors` co-sponsors before its voting period starts.
+		Draft,
+		/// Submitted by a non-member, waiting to be adopted for voting or dismissed.
+		Petition,
 	}
 
-	#[derive(PartialEq, Eq, Clone, Copy, Encode, Decode, TypeInfo, MaxEncodedLen)]
-	#[scale_info(skip_type_params(T))]
-	pub struct Proposal<T: Config> {
+	#[derive(PartialEq, Eq, Clone, Encode, Decode, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T, I))]
+	pub struct Proposal<T: Config<I>, I: 'static = ()> {
 		pub proposal_id: u32,
+		/// The group voting on the proposal.
+		pub group: GroupId,
 		pub threshold: ProposalThreshold,
 		pub status: ProposalStatus,
 		pub vote: u32,
 		pub proposaltype: ProposalType,
 		pub owner: T::AccountId,
+		/// Short human readable title shown to the signers.
+		pub title: BoundedVec<u8, <T as Config<I>>::MaxTitleLength>,
+		/// Hash of the full description, which is kept off chain.
+		pub description: Option<T::Hash>,
+		pub voting_mode: VotingMode,
+		/// Bumped whenever the owner amends the payload.
+		pub revision: u32,
+		/// How the votes are counted, the mode of the group when the proposal was created.
+		pub tally: TallyMode,
+	}
+
+	/// The identity of a group.
+	#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct GroupInfo<AccountId, BlockNumber, Name> {
+		/// Human readable name shown to the signers.
+		pub name: Name,
+		/// The account which created the group.
+		pub creator: AccountId,
+		/// The block the group was created at.
+		pub created_at: BlockNumber,
+	}
+
+	/// The votes of one of the groups of a joint proposal.
+	#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct GroupTally {
+		pub group_id: GroupId,
+		/// The members of the group who approved the proposal.
+		pub ayes: u32,
+		/// The members of the group who rejected the proposal.
+		pub nays: u32,
+		pub members: u32,
+		/// The approvals the group must reach.
+		pub threshold: u32,
+	}
+
+	/// How the members vote on a proposal.
+	#[derive(PartialEq, Eq, Clone, Copy, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum VotingMode {
+		/// Votes are visible and counted as soon as they are cast.
+		Open,
+		/// Members commit a salted hash of their vote and reveal it after the commit phase,
+		/// the votes are only counted once the reveal phase is over.
+		CommitReveal,
 	}
 
-	#[derive(PartialEq, Eq, Clone, Copy, Encode, Decode, TypeInfo, MaxEncodedLen)]
+	/// How the votes of a group are counted.
+	#[derive(
+		PartialEq, Eq, Clone, Copy, Default, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen,
+	)]
+	pub enum TallyMode {
+		/// Each member has one vote.
+		#[default]
+		HeadCount,
+		/// The members vote with the balance they freeze until the proposal is finished, the
+		/// threshold applies to the balance of all of the members.
+		TokenWeighted,
+	}
+
+	/// A call a group may dispatch, by the indices of its pallet and of the call in the pallet
+	/// in the runtime.
+	#[derive(PartialEq, Eq, Clone, Copy, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct AllowedCall {
+		pub pallet: u8,
+		/// `None` allows every call of the pallet.
+		pub call: Option<u8>,
+	}
+
+	/// How a proposal was finished.
+	#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+	pub enum ProposalOutcome {
+		/// The proposal was approved and executed, with the result of its call.
+		Executed(DispatchResult),
+		/// Enough members rejected the proposal.
+		Rejected,
+		/// The voting period ended before the proposal was approved.
+		Expired,
+		/// The owner cancelled the proposal.
+		Cancelled,
+		/// The proposal was approved but could not be executed.
+		ExecutionFailed(DispatchError),
+		/// The prerequisite proposal was finished without being executed successfully.
+		DependencyFailed(u32),
+		/// The votes cast approved the proposal, but too few members voted.
+		FailedQuorum,
+		/// A member dismissed the petition.
+		Dismissed,
+	}
+
+	/// The simulated execution of the call of a proposal.
+	#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+	pub struct DryRunResult<AccountId, Balance, Event> {
+		/// The result of the call dispatched by the group account.
+		pub result: DispatchResult,
+		/// The weight the call actually used.
+		pub actual_weight: Weight,
+		/// The events emitted by the call.
+		pub events: Vec<Event>,
+		/// The changed balances, as (account, balance before, balance after).
+		pub balances: Vec<(AccountId, Balance, Balance)>,
+	}
+
+	/// What a proposal changes, depending on its type.
+	#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+	pub enum ProposalPayload<AccountId, Call> {
+		/// The member added or removed.
+		Member(AccountId),
+		/// The replaced member and its replacement.
+		Swap(AccountId, AccountId),
+		/// The call dispatched by the group account.
+		Call(Call),
+	}
+
+	/// A replaced revision of a proposal.
+	#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+	pub struct ProposalRevision<AccountId, Call, BlockNumber> {
+		pub revision: u32,
+		pub payload: ProposalPayload<AccountId, Call>,
+		/// The block the revision was replaced at.
+		pub amended_at: BlockNumber,
+	}
+
+	/// Decides a proposal from the votes cast once its voting period is over.
+	#[derive(PartialEq, Eq, Clone, Copy, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct QuorumConfig {
+		/// The part of the members which must vote.
+		pub turnout: Percent,
+		/// The part of the votes cast which must approve.
+		pub approval: Percent,
+	}
+
+	/// A finished proposal kept in the history.
+	#[derive(PartialEq, Eq, Clone, Encode, Decode, TypeInfo)]
+	#[scale_info(skip_type_params(T, I))]
+	pub struct ProposalRecord<T: Config<I>, I: 'static = ()> {
+		pub proposal: Proposal<T, I>,
+		pub outcome: ProposalOutcome,
+		/// The block the proposal was finished at.
+		pub finished_at: BlockNumberFor<T>,
+	}
+
+	#[derive(PartialEq, Eq, Clone, Copy, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 	pub enum ProposalType {
 		AddMember,
 		RemoveMember,
+		Call,
+		FreezeGroup,
+		UnfreezeGroup,
+		SwapMember,
+		DissolveGroup,
+		SetCallFilter,
 		//etc
 	}
 
 	/// Info for keeping track of a motion being voted on.
 	#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
 	#[scale_info(skip_type_params(T))]
-	pub struct Votes<T: Config> {
+	pub struct Votes<T: frame_system::Config> {
 		/// The proposal's unique index.
 		pub index: ProposalIndex,
 		/// The number of approval votes that are needed to pass the motion.
@@ -145,8 +899,12 @@ pub mod pallet {
 		pub ayes: Vec<T::AccountId>,
 		/// The current set of voters that rejected it.
 		pub nays: Vec<T::AccountId>,
-		// /// The hard end time of this vote.
-		// end: T::BlockNumber,
+		/// The hard end time of this vote.
+		pub end: BlockNumberFor<T>,
+		/// The end of the commit phase for a `CommitReveal` proposal.
+		pub commit_end: Option<BlockNumberFor<T>>,
+		/// The votes cast by a delegate, as (delegator, delegate).
+		pub delegations: Vec<(T::AccountId, T::AccountId)>,
 	}
 
 	#[derive(Clone, PartialEq, Eq, Debug, Copy, Encode, Decode, TypeInfo, MaxEncodedLen)]
@@ -172,8 +930,8 @@ pub mod pallet {
 
 	// Errors inform users that something went wrong.
 	#[pallet::error]
-	pub enum Error<T> {
-		/// Error names should be descriptive.
+	pub enum Error<T, I = ()> {
+		/// The group has `MaxProposalNumber` pending proposals.
 		MaxProposalNumber,
 		MinMultisigNumber,
 		MaxMultisigNumber,
@@ -182,37 +940,211 @@ pub mod pallet {
 		NotFoundProposal,
 		NotFoundAddAccount,
 		NotFoundRemoveAccount,
+		NotFoundSwapAccount,
 		InvalidVote,
 		RepeatVoting,
+		/// The title is longer than `MaxTitleLength`.
+		TitleTooLong,
+		/// The call of the proposal is not found.
+		NotFoundCall,
+		/// Only the owner of the proposal can do this.
+		NotProposalOwner,
+		/// The proposal is voted with commit and reveal.
+		CommitRevealVoting,
+		/// The proposal is not voted with commit and reveal.
+		NotCommitRevealVoting,
+		/// The commit phase of the proposal is over.
+		NotCommitPhase,
+		/// The proposal is not in its reveal phase.
+		NotRevealPhase,
+		/// The member already committed a vote.
+		AlreadyCommitted,
+		/// The member has no commitment to reveal.
+		NotFoundCommitment,
+		/// The revealed vote does not match the commitment.
+		InvalidReveal,
+		/// Can not delegate to itself or until a past block.
+		InvalidDelegation,
+		/// The caller is not the delegate of the member.
+		NotDelegate,
+		/// The delegation period is over.
+		DelegationExpired,
+		/// The group is frozen.
+		GroupFrozen,
+		/// The group is not frozen.
+		NotFrozen,
+		/// A prerequisite is neither pending nor successfully executed.
+		InvalidPrerequisite,
+		/// The replaced account is not a member or its replacement already is.
+		InvalidSwap,
+		/// The account is already a member of the group.
+		AlreadyMember,
+		/// The name is longer than `MaxGroupNameLength`.
+		GroupNameTooLong,
+		/// The member has `MaxMemberProposalNumber` pending proposals.
+		MaxMemberProposalNumber,
+		/// Another member already voted on the proposal.
+		AlreadyVoted,
+		/// The amended payload does not match the type of the proposal.
+		InvalidAmendment,
+		/// The proposal is a draft waiting for sponsors.
+		DraftProposal,
+		/// The proposal is not a draft.
+		NotDraft,
+		/// The member already sponsored the proposal, the owner sponsors it by proposing it.
+		AlreadySponsored,
+		/// A dissolution needs between one and `MaxMultisigNumber` beneficiaries.
+		NoBeneficiary,
+		/// The threshold of `DissolveGroup` and `SetCallFilter` proposals is always unanimity.
+		FixedThreshold,
+		/// A joint proposal needs at least two distinct existing groups counting heads.
+		InvalidJointGroups,
+		/// The signature was not made by the Ethereum key over the vote.
+		InvalidEthSignature,
+		/// The proposal is voted with frozen balance, through `vote_with_balance`.
+		TokenWeightedVoting,
+		/// The proposal is not voted with frozen balance.
+		NotTokenWeighted,
+		/// The member can not vote with more than its balance.
+		InsufficientVotingBalance,
+		/// The proposal is a petition waiting to be adopted.
+		PetitionProposal,
+		/// The proposal is not a petition.
+		NotPetition,
+		/// A petition is decided by the members, its submitter can not cancel it.
+		CannotCancelPetition,
+		/// The call filter of the group does not allow the call.
+		CallNotAllowed,
+		/// `MaxGroupNumber` groups exist.
+		MaxGroupNumber,
+		/// The weight bound is below the weight of the calls the vote would execute.
+		WrongProposalWeight,
+		/// A proposal can not need more sponsors than the other members of the group.
+		TooManySponsors,
 	}
 
 	// when begin block or endblock  we need to deal with the proposal
 	#[pallet::hooks]
-	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+	impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I> {
+		// expire the proposals whose voting period is over, the weight includes the calls they
+		// execute
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			let mut weight = Weight::zero();
+
+			let expired = Proposals::<T, I>::iter()
+				.filter(|(proposal_id, _)| {
+					weight.saturating_accrue(T::DbWeight::get().reads(2));
+					Self::votings(proposal_id).is_some_and(|vote| vote.end < now)
+				})
+				.filter(|(_, proposal)| proposal.status != ProposalStatus::AwaitingDependencies)
+				.collect::<Vec<_>>();
+
+			for (proposal_id, proposal) in expired {
+				// finished meanwhile by a failed prerequisite
+				if !Proposals::<T, I>::contains_key(proposal_id) {
+					continue
+				}
+
+				let executed = match (Self::quorum_of(&proposal), proposal.voting_mode) {
+					// a petition which was not adopted forfeits its bond as a dismissed one
+					_ if proposal.status == ProposalStatus::Petition => {
+						let group_id = proposal.group;
+						let forfeited = Self::forfeit_petition_bond(proposal_id, group_id);
+						Self::deposit_event(Event::PetitionLapsed {
+							group_id,
+							proposal_id,
+							forfeited,
+						});
+						Self::finish_a_proposal(proposal_id, proposal, ProposalOutcome::Expired)
+					},
+					// a draft which was not sponsored within the voting period
+					_ if proposal.status == ProposalStatus::Draft =>
+						Self::finish_a_proposal(proposal_id, proposal, ProposalOutcome::Expired),
+					// joint, weighted and unanimous proposals are only decided by their votes
+					_ if JointGroups::<T, I>::contains_key(proposal_id) ||
+						Self::is_unanimous(proposal.proposaltype) ||
+						proposal.tally == TallyMode::TokenWeighted =>
+						Self::finish_a_proposal(proposal_id, proposal, ProposalOutcome::Expired),
+					(Some(quorum), _) => Self::close_with_quorum(proposal_id, proposal, quorum),
+					(None, VotingMode::Open) =>
+						Self::finish_a_proposal(proposal_id, proposal, ProposalOutcome::Expired),
+					// the reveal phase is over, count the revealed votes
+					(None, VotingMode::CommitReveal) =>
+						Self::close_commit_reveal(proposal_id, proposal),
+				};
+				// the calls executed by the closed proposals
+				weight.saturating_accrue(executed);
+				weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 6));
+			}
+
+			weight
+		}
+
+		// prune the oldest finished proposals once the history is over the limit, then look for
+		// inactive members with the weight left
+		fn on_idle(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let pruned = Self::prune_finished_proposals(remaining_weight);
+			pruned.saturating_add(Self::check_inactivity(
+				now,
+				remaining_weight.saturating_sub(pruned),
+			))
+		}
+	}
 
 	#[pallet::call]
-	impl<T: Config> Pallet<T> {
-		/// create multisig group
+	impl<T: Config<I>, I: 'static> Pallet<T, I> {
+		/// create a multisig group named `name`, the caller must be one of its members
+		///
+		/// An account can be a member of several groups, each group gets its own account. The
+		/// creator reserves `GroupDeposit` until the group is dissolved, at most `MaxGroupNumber`
+		/// groups exist at the same time.
 		#[pallet::call_index(0)]
 		#[pallet::weight(Weight::from_parts(1_000, 0))]
 		pub fn create_multisig_group(
 			origin: OriginFor<T>,
 			members: Vec<T::AccountId>,
+			name: Vec<u8>,
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
+			let name: BoundedVec<u8, T::MaxGroupNameLength> =
+				name.try_into().map_err(|_| Error::<T, I>::GroupNameTooLong)?;
+
 			let mut add_members = members.to_vec();
 
 			match !add_members.is_empty() && add_members.len() == members.len() {
-				false => return Err(Error::<T>::MinMultisigNumber.into()),
+				false => return Err(Error::<T, I>::MinMultisigNumber.into()),
 				true =>
 					if members.contains(&who) {
-						Self::change_multisig_members(&mut add_members, true)?;
+						ensure!(
+							members.len() as u32 >= T::MinMultisigNumber::get(),
+							Error::<T, I>::MinMultisigNumber
+						);
+						ensure!(
+							Groups::<T, I>::count() < T::MaxGroupNumber::get(),
+							Error::<T, I>::MaxGroupNumber
+						);
+
+						let deposit = T::GroupDeposit::get();
+						T::Currency::reserve(&who, deposit)?;
+
+						let group_id = Self::next_group_id();
+						Self::change_multisig_members(group_id, &mut add_members, true)?;
+						Self::track_members(group_id, &members, &[]);
+						Groups::<T, I>::insert(
+							group_id,
+							GroupInfo {
+								name,
+								creator: who.clone(),
+								created_at: frame_system::Pallet::<T>::block_number(),
+							},
+						);
+						GroupDeposits::<T, I>::insert(group_id, (who.clone(), deposit));
 						let dyn_threshold = Self::calculate_dyn_threshold(&members);
 
-						Self::deposit_event(Event::CreateMultisig { who, dyn_threshold });
+						Self::deposit_event(Event::CreateMultisig { group_id, who, dyn_threshold });
 					} else {
-						return Err(Error::<T>::MinMultisigNumber.into())
+						return Err(Error::<T, I>::MinMultisigNumber.into())
 					},
 			}
 
@@ -221,414 +1153,2704 @@ pub mod pallet {
 			Ok(())
 		}
 
-		/// create proposal
-		#[pallet::call_index(1)]
-		#[pallet::weight(Weight::from_parts(3_000, 0))]
-		pub fn create_proposal(
+		/// approve a proposal, the approval reaching its threshold executes it
+		///
+		/// `proposal_weight_bound` must cover the weight of the calls executed, see
+		/// `execution_weight`, only the weight they used is charged.
+		#[pallet::call_index(2)]
+		#[pallet::weight(Weight::from_parts(3_000, 0).saturating_add(*proposal_weight_bound))]
+		pub fn approve(
 			origin: OriginFor<T>,
-			threshold: u32,
-			proposaltype: u32,
-		) -> DispatchResult {
-			let who = ensure_signed(origin.clone())?;
+			proposal_id: u32,
+			proposal_weight_bound: Weight,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let mut executed = Weight::zero();
 
-			// does account contain the multisig group?
-			match MultisigMembers::<T>::get().contains(&who) {
-				true => {
-					let multisig_members = MultisigMembers::<T>::get();
-					let multisig_members_len = multisig_members.len();
+			Self::ensure_open_voting(proposal_id)?;
 
-					if multisig_members_len > 5 {
-						return Err(Error::<T>::MaxProposalNumber.into())
-					} else {
-						let proposal_id = Proposals::<T>::iter().count() as u32 + 1;
+			match Self::is_voter(proposal_id, &who)? {
+				true => {
+					// vote for proposal and execute the proposal if vote had enough approval
 
-						let vote: Votes<T> = Votes {
-							index: proposal_id,
-							threshold,
-							ayes: vec![who.clone()],
-							nays: Vec::new(),
-						};
+					let dyn_threshold = Self::approval_threshold(proposal_id);
 
-						Voting::<T>::insert(proposal_id, &vote);
+					let should_execute =
+						Self::do_vote(who.clone(), proposal_id, true, dyn_threshold)?;
 
-						let threshold = match threshold {
-							1 => ProposalThreshold::All,
-							2 => ProposalThreshold::MoreThanhalf,
-							_ => ProposalThreshold::MoreThanTwoThirds,
-						};
+					if should_execute {
+						executed = Self::execute_within(proposal_id, proposal_weight_bound)?;
+					}
+				},
+				false => return Err(Error::<T, I>::MustContainCaller.into()),
+			}
 
-						let protype = match proposaltype {
-							1 => ProposalType::AddMember,
-							_ => ProposalType::RemoveMember,
-						};
+			//todo! check if proposal exists
+			Ok(Some(Weight::from_parts(3_000, 0).saturating_add(executed)).into())
+		}
 
-						let status = ProposalStatus::Pending;
+		#[pallet::call_index(3)]
+		#[pallet::weight(Weight::from_parts(3_000, 0))]
+		pub fn reject(origin: OriginFor<T>, proposal_id: u32) -> DispatchResult {
+			let who = ensure_signed(origin)?;
 
-						let proposal = Proposal {
-							proposal_id,
-							threshold,
-							status,
-							vote: 1,
-							proposaltype: protype,
-							owner: who.clone(),
-						};
+			Self::ensure_open_voting(proposal_id)?;
+
+			match Self::is_voter(proposal_id, &who)? {
+				true => {
+					//only reject the proposal
+					let dyn_threshold = Self::approval_threshold(proposal_id);
+					Self::do_vote(who.clone(), proposal_id, false, dyn_threshold)?;
+				},
+				false => return Err(Error::<T, I>::MustContainCaller.into()),
+			}
 
-						Proposals::<T>::insert(proposal_id, &proposal);
+			Ok(())
+		}
 
-						Self::approve(origin, proposal_id)?;
+		// remove member from multisig
+		#[pallet::call_index(4)]
+		#[pallet::weight(Weight::from_parts(3_000, 0))]
+		pub fn remove_member(
+			origin: OriginFor<T>,
+			group_id: GroupId,
+			member: T::AccountId,
+			voting_mode: VotingMode,
+		) -> DispatchResult {
+			let who = ensure_signed(origin.clone())?;
 
-						Self::deposit_event(Event::CreateProposal {
-							who,
-							proposal_id,
-							threshold,
-							status,
-						});
-					}
+			// check the sender  in multisig group
+			match MultisigMembers::<T, I>::get(group_id).contains(&who) &&
+				MultisigMembers::<T, I>::get(group_id).contains(&member)
+			{
+				true => {
+					ensure!(Self::can_remove_member(group_id), Error::<T, I>::MinMultisigNumber);
+
+					// just create remove member proposal
+					Self::create_a_proposal(
+						group_id,
+						who,
+						ProposalType::RemoveMember,
+						Some(member),
+						Default::default(),
+						None,
+						voting_mode,
+					)?;
 				},
-				false => return Err(Error::<T>::NotFoundAccount.into()),
+
+				false => return Err(Error::<T, I>::NotFoundAccount.into()),
 			}
 
-			// Return a successful DispatchResultWithPostInfo
+			//todo ! check if member exists
 			Ok(())
 		}
 
-		#[pallet::call_index(2)]
+		#[pallet::call_index(5)]
 		#[pallet::weight(Weight::from_parts(3_000, 0))]
-		pub fn approve(origin: OriginFor<T>, proposal_id: u32) -> DispatchResult {
-			let who = ensure_signed(origin)?;
+		pub fn add_member(
+			origin: OriginFor<T>,
+			group_id: GroupId,
+			member: T::AccountId,
+			voting_mode: VotingMode,
+		) -> DispatchResult {
+			let who = ensure_signed(origin.clone())?;
 
-			match MultisigMembers::<T>::get().contains(&who) {
+			// check the sender  in multisig group
+			match MultisigMembers::<T, I>::get(group_id).contains(&who) &&
+				!MultisigMembers::<T, I>::get(group_id).contains(&member)
+			{
 				true => {
-					// vote for proposal and execute the proposal if vote had enough approval
+					// just create add member proposal
+					Self::create_a_proposal(
+						group_id,
+						who,
+						ProposalType::AddMember,
+						Some(member),
+						Default::default(),
+						None,
+						voting_mode,
+					)?;
+				},
+				false => return Err(Error::<T, I>::NotFoundAccount.into()),
+			}
 
-					let dyn_threshold = Self::calculate_dyn_threshold(&MultisigMembers::<T>::get());
+			//todo ! check if member exists
+			Ok(())
+		}
 
-					let should_execute =
-						Self::do_vote(who.clone(), proposal_id, true, dyn_threshold)?;
+		/// create a proposal dispatching `call` from the group account
+		///
+		/// The call is noted as a preimage and only its hash is kept with the proposal, a
+		/// deposit depending on the length of the call is reserved until the proposal finished.
+		///
+		/// The proposal is only executed after all of the `prerequisites` proposals of the group
+		/// were executed, it fails if one of them is finished in any other way.
+		#[pallet::call_index(6)]
+		#[pallet::weight(Weight::from_parts(5_000, 0))]
+		pub fn propose_call(
+			origin: OriginFor<T>,
+			group_id: GroupId,
+			title: Vec<u8>,
+			description: Option<T::Hash>,
+			call: Box<<T as Config<I>>::RuntimeCall>,
+			voting_mode: VotingMode,
+			prerequisites: Vec<u32>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
 
-					if should_execute {
-						Self::exe_proposal(proposal_id)?;
-					}
-				},
-				false => return Err(Error::<T>::MustContainCaller.into()),
+			ensure!(
+				MultisigMembers::<T, I>::get(group_id).contains(&who),
+				Error::<T, I>::NotFoundAccount
+			);
+
+			let title: BoundedVec<u8, T::MaxTitleLength> =
+				title.try_into().map_err(|_| Error::<T, I>::TitleTooLong)?;
+
+			let prerequisites = Self::outstanding_prerequisites(group_id, prerequisites)?;
+
+			let proposal_id = Self::create_a_proposal(
+				group_id,
+				who.clone(),
+				ProposalType::Call,
+				None,
+				title,
+				description,
+				voting_mode,
+			)?;
+
+			Self::note_call(&who, proposal_id, &call)?;
+
+			for prerequisite in &prerequisites {
+				Dependents::<T, I>::append(prerequisite, proposal_id);
+			}
+			if !prerequisites.is_empty() {
+				Prerequisites::<T, I>::insert(proposal_id, prerequisites);
 			}
 
-			//todo! check if proposal exists
 			Ok(())
 		}
 
-		#[pallet::call_index(3)]
+		/// cancel a pending proposal, only the owner can cancel it
+		#[pallet::call_index(7)]
 		#[pallet::weight(Weight::from_parts(3_000, 0))]
-		pub fn reject(origin: OriginFor<T>, proposal_id: u32) -> DispatchResult {
+		pub fn cancel_proposal(origin: OriginFor<T>, proposal_id: u32) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
-			match MultisigMembers::<T>::get().contains(&who) {
-				true => {
-					//only reject the proposal
-					let dyn_threshold = Self::calculate_dyn_threshold(&MultisigMembers::<T>::get());
-					Self::do_vote(who.clone(), proposal_id, false, dyn_threshold)?;
+			let proposal = Self::proposals(proposal_id).ok_or(Error::<T, I>::NotFoundProposal)?;
+			ensure!(proposal.owner == who, Error::<T, I>::NotProposalOwner);
+			// the bond of a petition is only returned or forfeited by the members
+			ensure!(
+				!PetitionBonds::<T, I>::contains_key(proposal_id),
+				Error::<T, I>::CannotCancelPetition
+			);
+
+			Self::finish_a_proposal(proposal_id, proposal, ProposalOutcome::Cancelled);
+
+			Ok(())
+		}
+
+		/// commit the salted hash of a vote on a `CommitReveal` proposal
+		///
+		/// The commitment is the hash of `(who, proposal_id, approve, salt)`.
+		#[pallet::call_index(8)]
+		#[pallet::weight(Weight::from_parts(3_000, 0))]
+		pub fn commit_vote(
+			origin: OriginFor<T>,
+			proposal_id: u32,
+			commitment: T::Hash,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let group_id = Self::group_of(proposal_id)?;
+			ensure!(
+				MultisigMembers::<T, I>::get(group_id).contains(&who),
+				Error::<T, I>::MustContainCaller
+			);
+
+			let commit_end = Self::commit_end_of(proposal_id)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() <= commit_end,
+				Error::<T, I>::NotCommitPhase
+			);
+			if let Some(proposal) = Self::proposals(proposal_id) {
+				Self::ensure_not_frozen(group_id, proposal.proposaltype)?;
+				ensure!(proposal.status != ProposalStatus::Draft, Error::<T, I>::DraftProposal);
+				ensure!(
+					proposal.status != ProposalStatus::Petition,
+					Error::<T, I>::PetitionProposal
+				);
+			}
+			ensure!(
+				!Commitments::<T, I>::contains_key(proposal_id, &who),
+				Error::<T, I>::AlreadyCommitted
+			);
+
+			Commitments::<T, I>::insert(proposal_id, &who, commitment);
+			LastActive::<T, I>::insert(group_id, &who, frame_system::Pallet::<T>::block_number());
+
+			Self::deposit_event(Event::VoteCommitted { group_id, proposal_id, who });
+
+			Ok(())
+		}
+
+		/// reveal a committed vote, it is counted once the reveal phase is over
+		#[pallet::call_index(9)]
+		#[pallet::weight(Weight::from_parts(3_000, 0))]
+		pub fn reveal_vote(
+			origin: OriginFor<T>,
+			proposal_id: u32,
+			approve: bool,
+			salt: [u8; 32],
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let group_id = Self::group_of(proposal_id)?;
+			ensure!(
+				MultisigMembers::<T, I>::get(group_id).contains(&who),
+				Error::<T, I>::MustContainCaller
+			);
+
+			let commit_end = Self::commit_end_of(proposal_id)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() > commit_end,
+				Error::<T, I>::NotRevealPhase
+			);
+
+			let commitment = Commitments::<T, I>::get(proposal_id, &who)
+				.ok_or(Error::<T, I>::NotFoundCommitment)?;
+			ensure!(
+				T::Hashing::hash_of(&(&who, proposal_id, approve, salt)) == commitment,
+				Error::<T, I>::InvalidReveal
+			);
+
+			Commitments::<T, I>::remove(proposal_id, &who);
+
+			let dyn_threshold = Self::approval_threshold(proposal_id);
+			Self::do_vote(who.clone(), proposal_id, approve, dyn_threshold)?;
+
+			Self::deposit_event(Event::VoteRevealed { group_id, proposal_id, who, approve });
+
+			Ok(())
+		}
+
+		/// let another member vote on behalf of the caller in the group until `until_block`
+		///
+		/// The delegation ends once the period is over or either member leaves the group.
+		#[pallet::call_index(10)]
+		#[pallet::weight(Weight::from_parts(3_000, 0))]
+		pub fn delegate_vote(
+			origin: OriginFor<T>,
+			group_id: GroupId,
+			to: T::AccountId,
+			until_block: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let members = MultisigMembers::<T, I>::get(group_id);
+			ensure!(
+				members.contains(&who) && members.contains(&to),
+				Error::<T, I>::NotFoundAccount
+			);
+			ensure!(
+				who != to && until_block >= frame_system::Pallet::<T>::block_number(),
+				Error::<T, I>::InvalidDelegation
+			);
+
+			Delegations::<T, I>::insert(group_id, &who, (to.clone(), until_block));
+
+			Self::deposit_event(Event::VoteDelegated { group_id, who, to, until: until_block });
+
+			Ok(())
+		}
+
+		/// end the delegation of the caller in the group
+		#[pallet::call_index(11)]
+		#[pallet::weight(Weight::from_parts(3_000, 0))]
+		pub fn undelegate_vote(origin: OriginFor<T>, group_id: GroupId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(Delegations::<T, I>::contains_key(group_id, &who), Error::<T, I>::NotDelegate);
+
+			Delegations::<T, I>::remove(group_id, &who);
+
+			Self::deposit_event(Event::DelegationRemoved { group_id, who });
+
+			Ok(())
+		}
+
+		/// vote on behalf of `delegator`, the vote is attributed to both members
+		///
+		/// `proposal_weight_bound` must cover the calls the vote executes, as for `approve`.
+		#[pallet::call_index(12)]
+		#[pallet::weight(Weight::from_parts(3_000, 0).saturating_add(*proposal_weight_bound))]
+		pub fn vote_as_delegate(
+			origin: OriginFor<T>,
+			delegator: T::AccountId,
+			proposal_id: u32,
+			approve: bool,
+			proposal_weight_bound: Weight,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			Self::ensure_open_voting(proposal_id)?;
+			let group_id = Self::group_of(proposal_id)?;
+
+			let members = MultisigMembers::<T, I>::get(group_id);
+			ensure!(members.contains(&who), Error::<T, I>::MustContainCaller);
+			ensure!(members.contains(&delegator), Error::<T, I>::NotFoundAccount);
+
+			let (delegate, until) =
+				Self::delegations(group_id, &delegator).ok_or(Error::<T, I>::NotDelegate)?;
+			ensure!(delegate == who, Error::<T, I>::NotDelegate);
+			ensure!(
+				frame_system::Pallet::<T>::block_number() <= until,
+				Error::<T, I>::DelegationExpired
+			);
+
+			let dyn_threshold = Self::approval_threshold(proposal_id);
+			let should_execute =
+				Self::do_vote(delegator.clone(), proposal_id, approve, dyn_threshold)?;
+
+			Voting::<T, I>::mutate(proposal_id, |vote| {
+				if let Some(vote) = vote {
+					vote.delegations.push((delegator.clone(), who.clone()));
+				}
+			});
+
+			Self::deposit_event(Event::DelegatedVote {
+				group_id,
+				proposal_id,
+				delegator,
+				delegate: who,
+				approve,
+			});
+
+			let mut executed = Weight::zero();
+			if should_execute {
+				executed = Self::execute_within(proposal_id, proposal_weight_bound)?;
+			}
+
+			Ok(Some(Weight::from_parts(3_000, 0).saturating_add(executed)).into())
+		}
+
+		/// create a proposal freezing the group, approved by its threshold, `FreezeThreshold` by
+		/// default
+		///
+		/// While the group is frozen no proposal can be created, voted on or executed, except
+		/// for an `unfreeze_group` proposal.
+		#[pallet::call_index(13)]
+		#[pallet::weight(Weight::from_parts(3_000, 0))]
+		pub fn freeze_group(origin: OriginFor<T>, group_id: GroupId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::create_a_proposal(
+				group_id,
+				who,
+				ProposalType::FreezeGroup,
+				None,
+				Default::default(),
+				None,
+				VotingMode::Open,
+			)?;
+
+			Ok(())
+		}
+
+		/// create a proposal unfreezing the group
+		#[pallet::call_index(14)]
+		#[pallet::weight(Weight::from_parts(3_000, 0))]
+		pub fn unfreeze_group(origin: OriginFor<T>, group_id: GroupId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(Frozen::<T, I>::contains_key(group_id), Error::<T, I>::NotFrozen);
+
+			Self::create_a_proposal(
+				group_id,
+				who,
+				ProposalType::UnfreezeGroup,
+				None,
+				Default::default(),
+				None,
+				VotingMode::Open,
+			)?;
+
+			Ok(())
+		}
+
+		/// propose to replace the member `old` by `new`, to rotate a key or recover a lost one
+		///
+		/// The group size does not change, the votes, delegations and proposals of `old` are
+		/// moved to `new`. It can be executed while the group is frozen.
+		#[pallet::call_index(15)]
+		#[pallet::weight(Weight::from_parts(3_000, 0))]
+		pub fn swap_member(
+			origin: OriginFor<T>,
+			group_id: GroupId,
+			old: T::AccountId,
+			new: T::AccountId,
+			voting_mode: VotingMode,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let members = MultisigMembers::<T, I>::get(group_id);
+			ensure!(members.contains(&who), Error::<T, I>::NotFoundAccount);
+			ensure!(members.contains(&old) && !members.contains(&new), Error::<T, I>::InvalidSwap);
+
+			let proposal_id = Self::create_a_proposal(
+				group_id,
+				who,
+				ProposalType::SwapMember,
+				None,
+				Default::default(),
+				None,
+				voting_mode,
+			)?;
+
+			SwapMember::<T, I>::insert(proposal_id, (old, new));
+
+			Ok(())
+		}
+
+		/// set the quorum deciding the proposals of the group once their voting period is over,
+		/// `None` lets the votes decide them as they are cast
+		///
+		/// Joint, unanimous and token weighted proposals, and the ones freezing, unfreezing or
+		/// swapping, are always decided by their votes. The others also need the approvals of
+		/// their threshold.
+		///
+		/// Must be dispatched by the group account, through a call proposal.
+		#[pallet::call_index(16)]
+		#[pallet::weight(Weight::from_parts(2_000, 0))]
+		pub fn set_quorum(
+			origin: OriginFor<T>,
+			group_id: GroupId,
+			quorum: Option<QuorumConfig>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(who == Self::account_id(group_id), DispatchError::BadOrigin);
+
+			Quorum::<T, I>::set(group_id, quorum);
+
+			Self::deposit_event(Event::QuorumSet { group_id, quorum });
+
+			Ok(())
+		}
+
+		/// amend the payload of a pending proposal, only the owner can amend it while no other
+		/// member voted on it
+		///
+		/// The revision of the proposal is bumped and its votes are reset, the replaced payload
+		/// is kept in `Revisions`. The owner approves the new revision as a new proposal.
+		#[pallet::call_index(17)]
+		#[pallet::weight(Weight::from_parts(3_000, 0))]
+		pub fn amend_proposal(
+			origin: OriginFor<T>,
+			proposal_id: u32,
+			payload: ProposalPayload<T::AccountId, Box<<T as Config<I>>::RuntimeCall>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let mut proposal =
+				Self::proposals(proposal_id).ok_or(Error::<T, I>::NotFoundProposal)?;
+			ensure!(proposal.owner == who, Error::<T, I>::NotProposalOwner);
+			ensure!(
+				matches!(proposal.status, ProposalStatus::Pending | ProposalStatus::Draft),
+				Error::<T, I>::AlreadyVoted
+			);
+			Self::ensure_not_frozen(proposal.group, proposal.proposaltype)?;
+
+			let mut vote = Self::votings(proposal_id).ok_or(Error::<T, I>::NotFoundProposal)?;
+			ensure!(
+				vote.nays.is_empty() &&
+					vote.ayes.iter().all(|aye| aye == &who) &&
+					Commitments::<T, I>::iter_key_prefix(proposal_id)
+						.all(|member| member == who),
+				Error::<T, I>::AlreadyVoted
+			);
+
+			let previous = Self::payload_of(proposal_id, proposal.proposaltype)
+				.ok_or(Error::<T, I>::InvalidAmendment)?;
+
+			let members = MultisigMembers::<T, I>::get(proposal.group);
+			match (proposal.proposaltype, payload) {
+				(ProposalType::AddMember, ProposalPayload::Member(member)) => {
+					ensure!(!members.contains(&member), Error::<T, I>::AlreadyMember);
+					AddMember::<T, I>::insert(proposal_id, member);
+				},
+				(ProposalType::RemoveMember, ProposalPayload::Member(member)) => {
+					ensure!(members.contains(&member), Error::<T, I>::NotFoundRemoveAccount);
+					if let Some(previous) = Self::remove_members(proposal_id) {
+						Self::count_removal(proposal.group, &previous, false);
+					}
+					Self::count_removal(proposal.group, &member, true);
+					RemoveMember::<T, I>::insert(proposal_id, member);
+				},
+				(ProposalType::SwapMember, ProposalPayload::Swap(old, new)) => {
+					ensure!(
+						members.contains(&old) && !members.contains(&new),
+						Error::<T, I>::InvalidSwap
+					);
+					SwapMember::<T, I>::insert(proposal_id, (old, new));
+				},
+				(ProposalType::Call, ProposalPayload::Call(call)) => {
+					// the replaced call stays requested for its revision until the record is pruned
+					CallProposal::<T, I>::remove(proposal_id);
+					Self::clear_call(proposal_id);
+					Self::note_call(&who, proposal_id, &call)?;
+				},
+				_ => return Err(Error::<T, I>::InvalidAmendment.into()),
+			}
+
+			Revisions::<T, I>::append(
+				proposal_id,
+				ProposalRevision {
+					revision: proposal.revision,
+					payload: previous,
+					amended_at: frame_system::Pallet::<T>::block_number(),
 				},
-				false => return Err(Error::<T>::MustContainCaller.into()),
+			);
+
+			proposal.revision = proposal.revision.saturating_add(1);
+			proposal.vote = 0;
+			Proposals::<T, I>::insert(proposal_id, &proposal);
+
+			vote.ayes.clear();
+			vote.delegations.clear();
+			Voting::<T, I>::insert(proposal_id, &vote);
+			let _ = Commitments::<T, I>::clear_prefix(proposal_id, u32::MAX, None);
+			Self::release_weighted_votes(proposal_id);
+			// the sponsors backed the replaced revision
+			Sponsors::<T, I>::remove(proposal_id);
+
+			Self::deposit_event(Event::ProposalAmended {
+				group_id: proposal.group,
+				proposal_id,
+				revision: proposal.revision,
+			});
+
+			if proposal.status == ProposalStatus::Pending &&
+				proposal.voting_mode == VotingMode::Open &&
+				proposal.tally == TallyMode::HeadCount
+			{
+				Self::do_vote(who, proposal_id, true, Self::approval_threshold(proposal_id))?;
 			}
 
 			Ok(())
 		}
 
-		// remove member from multisig
-		#[pallet::call_index(4)]
+		/// set the co-sponsors a proposal needs before it is opened for voting, `0` opens the
+		/// proposals right away
+		///
+		/// A draft which is not sponsored within `VotingPeriod` expires.
+		///
+		/// Must be dispatched by the group account, through a call proposal.
+		#[pallet::call_index(18)]
+		#[pallet::weight(Weight::from_parts(2_000, 0))]
+		pub fn set_required_sponsors(
+			origin: OriginFor<T>,
+			group_id: GroupId,
+			count: u32,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(who == Self::account_id(group_id), DispatchError::BadOrigin);
+			// the owner does not sponsor its own proposal
+			let members = MultisigMembers::<T, I>::decode_len(group_id).unwrap_or_default() as u32;
+			ensure!(count <= members.saturating_sub(1), Error::<T, I>::TooManySponsors);
+
+			RequiredSponsors::<T, I>::insert(group_id, count);
+
+			Self::deposit_event(Event::RequiredSponsorsSet { group_id, count });
+
+			Ok(())
+		}
+
+		/// sponsor a draft proposal, it is opened for voting once it has `RequiredSponsors`
+		/// sponsors
+		#[pallet::call_index(19)]
 		#[pallet::weight(Weight::from_parts(3_000, 0))]
-		pub fn remove_member(origin: OriginFor<T>, member: T::AccountId) -> DispatchResult {
-			let who = ensure_signed(origin.clone())?;
+		pub fn sponsor(origin: OriginFor<T>, proposal_id: u32) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let proposal = Self::proposals(proposal_id).ok_or(Error::<T, I>::NotFoundProposal)?;
+			ensure!(
+				MultisigMembers::<T, I>::get(proposal.group).contains(&who),
+				Error::<T, I>::MustContainCaller
+			);
+			ensure!(proposal.status == ProposalStatus::Draft, Error::<T, I>::NotDraft);
+			Self::ensure_not_frozen(proposal.group, proposal.proposaltype)?;
+
+			let mut sponsors = Self::sponsors(proposal_id);
+			ensure!(
+				proposal.owner != who && !sponsors.contains(&who),
+				Error::<T, I>::AlreadySponsored
+			);
+			sponsors.push(who.clone());
+
+			Self::deposit_event(Event::ProposalSponsored {
+				group_id: proposal.group,
+				proposal_id,
+				who,
+			});
+
+			if sponsors.len() as u32 >= Self::required_sponsors(proposal.group) {
+				Sponsors::<T, I>::remove(proposal_id);
+				let owner = proposal.owner.clone();
+				Self::open_proposal(proposal_id, proposal, owner)?;
+			} else {
+				Sponsors::<T, I>::insert(proposal_id, sponsors);
+			}
+
+			Ok(())
+		}
+
+		/// set the threshold the proposals of `proposaltype` are created with, `None` restores
+		/// the default of the type
+		///
+		/// Must be dispatched by the group account, through a call proposal. The pending
+		/// proposals keep their threshold.
+		#[pallet::call_index(20)]
+		#[pallet::weight(Weight::from_parts(2_000, 0))]
+		pub fn set_threshold(
+			origin: OriginFor<T>,
+			group_id: GroupId,
+			proposaltype: ProposalType,
+			threshold: Option<ProposalThreshold>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(who == Self::account_id(group_id), DispatchError::BadOrigin);
+			ensure!(!Self::is_unanimous(proposaltype), Error::<T, I>::FixedThreshold);
+
+			Thresholds::<T, I>::set(group_id, proposaltype, threshold);
+
+			Self::deposit_event(Event::ThresholdSet {
+				group_id,
+				proposaltype,
+				threshold: Self::threshold_of(group_id, proposaltype),
+			});
+
+			Ok(())
+		}
+
+		/// propose to dissolve the group, all of the members must approve it
+		///
+		/// The funds of the group account are shared equally by the `beneficiaries`, the first
+		/// one gets the remainder. The members, the pending proposals and the settings of the
+		/// group are cleared and the deposits are returned, only the record of this proposal
+		/// is kept in the history of the group.
+		#[pallet::call_index(21)]
+		#[pallet::weight(Weight::from_parts(5_000, 0))]
+		pub fn dissolve_group(
+			origin: OriginFor<T>,
+			group_id: GroupId,
+			beneficiaries: Vec<T::AccountId>,
+			voting_mode: VotingMode,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let beneficiaries: BoundedVec<T::AccountId, T::MaxMultisigNumber> =
+				beneficiaries.try_into().map_err(|_| Error::<T, I>::NoBeneficiary)?;
+			ensure!(!beneficiaries.is_empty(), Error::<T, I>::NoBeneficiary);
+
+			let proposal_id = Self::create_a_proposal(
+				group_id,
+				who,
+				ProposalType::DissolveGroup,
+				None,
+				Default::default(),
+				None,
+				voting_mode,
+			)?;
+
+			DissolveBeneficiaries::<T, I>::insert(proposal_id, beneficiaries);
+
+			Ok(())
+		}
+
+		/// propose a call approved by several groups, each reaching its own threshold
+		///
+		/// The first of `groups` proposes it, the caller must be one of its members. The members
+		/// of every group vote on the proposal, which is executed once each group reached its
+		/// threshold and rejected once one of them can not reach it anymore. The call is
+		/// dispatched by the joint account of the groups, see `joint_account_id`.
+		#[pallet::call_index(22)]
+		#[pallet::weight(Weight::from_parts(5_000, 0))]
+		pub fn propose_joint_call(
+			origin: OriginFor<T>,
+			groups: Vec<(GroupId, ProposalThreshold)>,
+			title: Vec<u8>,
+			description: Option<T::Hash>,
+			call: Box<<T as Config<I>>::RuntimeCall>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let mut group_ids = groups.iter().map(|(group_id, _)| *group_id).collect::<Vec<_>>();
+			group_ids.sort();
+			group_ids.dedup();
+			ensure!(
+				group_ids.len() >= 2 &&
+					group_ids.len() == groups.len() &&
+					group_ids.iter().all(|group_id| {
+						Groups::<T, I>::contains_key(group_id) &&
+							Self::tally_mode(group_id) == TallyMode::HeadCount
+					}),
+				Error::<T, I>::InvalidJointGroups
+			);
+			for group_id in &group_ids {
+				Self::ensure_not_frozen(*group_id, ProposalType::Call)?;
+			}
+
+			let (group_id, threshold) = groups[0];
+			ensure!(
+				MultisigMembers::<T, I>::get(group_id).contains(&who),
+				Error::<T, I>::NotFoundAccount
+			);
+
+			let title: BoundedVec<u8, T::MaxTitleLength> =
+				title.try_into().map_err(|_| Error::<T, I>::TitleTooLong)?;
+
+			// the proposal is created with the threshold of its first group
+			let proposal_id = Self::new_proposal(
+				group_id,
+				who.clone(),
+				ProposalType::Call,
+				None,
+				title,
+				description,
+				VotingMode::Open,
+				Some(threshold),
+				false,
+			)?;
+
+			// the call must be allowed by each of the groups
+			JointGroups::<T, I>::insert(proposal_id, &groups);
+			Self::note_call(&who, proposal_id, &call)?;
+
+			Self::deposit_event(Event::JointProposalCreated {
+				proposal_id,
+				groups,
+				account: Self::joint_account_id(group_ids),
+			});
+			Self::deposit_event(Event::JointVoteTallied {
+				proposal_id,
+				tallies: Self::joint_tallies(proposal_id),
+			});
+
+			Ok(())
+		}
+
+		/// count the votes of the group with `mode` from its next proposal on
+		///
+		/// Must be dispatched by the group account, through a call proposal.
+		#[pallet::call_index(24)]
+		#[pallet::weight(Weight::from_parts(2_000, 0))]
+		pub fn set_tally_mode(
+			origin: OriginFor<T>,
+			group_id: GroupId,
+			mode: TallyMode,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(who == Self::account_id(group_id), DispatchError::BadOrigin);
+
+			TallyModes::<T, I>::insert(group_id, mode);
+
+			Self::deposit_event(Event::TallyModeSet { group_id, mode });
+
+			Ok(())
+		}
+
+		/// vote on a `TokenWeighted` proposal with `amount` of the balance of the caller
+		///
+		/// The amount is frozen until the proposal is finished, the same balance can back the
+		/// votes on several proposals. `proposal_weight_bound` must cover the calls the vote
+		/// executes, as for `approve`.
+		#[pallet::call_index(25)]
+		#[pallet::weight(Weight::from_parts(5_000, 0).saturating_add(*proposal_weight_bound))]
+		pub fn vote_with_balance(
+			origin: OriginFor<T>,
+			proposal_id: u32,
+			approve: bool,
+			amount: BalanceOf<T, I>,
+			proposal_weight_bound: Weight,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let proposal = Self::proposals(proposal_id).ok_or(Error::<T, I>::NotFoundProposal)?;
+			ensure!(proposal.tally == TallyMode::TokenWeighted, Error::<T, I>::NotTokenWeighted);
+			ensure!(
+				MultisigMembers::<T, I>::get(proposal.group).contains(&who),
+				Error::<T, I>::MustContainCaller
+			);
+			ensure!(
+				!amount.is_zero() && amount <= T::Currency::total_balance(&who),
+				Error::<T, I>::InsufficientVotingBalance
+			);
+			ensure!(
+				!WeightedVotes::<T, I>::contains_key(proposal_id, &who),
+				Error::<T, I>::RepeatVoting
+			);
+
+			T::Freezer::extend_freeze(&T::FreezeId::get(), &who, amount)?;
+			WeightedVotes::<T, I>::insert(proposal_id, &who, (approve, amount));
+
+			let should_execute =
+				Self::do_vote(who, proposal_id, approve, Self::approval_threshold(proposal_id))?;
+
+			let mut executed = Weight::zero();
+			if should_execute {
+				executed = Self::execute_within(proposal_id, proposal_weight_bound)?;
+			}
+
+			Ok(Some(Weight::from_parts(5_000, 0).saturating_add(executed)).into())
+		}
+
+		/// vote with the signature of an Ethereum key, submitted unsigned
+		///
+		/// The key signs the EIP-191 message of `eth_vote_hash`, the vote is cast for its
+		/// member account `eth_account_id(signer)` which does not need to hold any funds.
+		/// `proposal_weight_bound` must cover the calls of the proposal, as for `approve`.
+		#[pallet::call_index(23)]
+		#[pallet::weight(Weight::from_parts(5_000, 0).saturating_add(*proposal_weight_bound))]
+		pub fn approve_with_eth(
+			origin: OriginFor<T>,
+			proposal_id: u32,
+			approve: bool,
+			signer: H160,
+			signature: [u8; 65],
+			proposal_weight_bound: Weight,
+		) -> DispatchResultWithPostInfo {
+			ensure_none(origin)?;
+
+			let who = Self::check_eth_vote(proposal_id, approve, &signer, &signature)?;
+			let group_id = Self::group_of(proposal_id)?;
+
+			let dyn_threshold = Self::approval_threshold(proposal_id);
+			let should_execute = Self::do_vote(who.clone(), proposal_id, approve, dyn_threshold)?;
+
+			Self::deposit_event(Event::EthVoteCast { group_id, proposal_id, signer, who, approve });
+
+			let mut executed = Weight::zero();
+			if should_execute {
+				executed = Self::execute_within(proposal_id, proposal_weight_bound)?;
+			}
+
+			Ok(Some(Weight::from_parts(5_000, 0).saturating_add(executed)).into())
+		}
+
+		/// ask the group to dispatch `call`, any account can submit a petition
+		///
+		/// `PetitionBond` is reserved along with the deposit of the call until the petition is
+		/// finished, the submitter can not cancel it. The petition counts against the
+		/// `MaxProposalNumber` of the group, it lapses if no member adopts it within
+		/// `VotingPeriod` and forfeits its bond as a dismissed petition.
+		#[pallet::call_index(26)]
+		#[pallet::weight(Weight::from_parts(5_000, 0))]
+		pub fn submit_petition(
+			origin: OriginFor<T>,
+			group_id: GroupId,
+			title: Vec<u8>,
+			description: Option<T::Hash>,
+			call: Box<<T as Config<I>>::RuntimeCall>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(Groups::<T, I>::contains_key(group_id), Error::<T, I>::NotFoundAccount);
+			ensure!(
+				!MultisigMembers::<T, I>::get(group_id).contains(&who),
+				Error::<T, I>::AlreadyMember
+			);
+
+			let title: BoundedVec<u8, T::MaxTitleLength> =
+				title.try_into().map_err(|_| Error::<T, I>::TitleTooLong)?;
+
+			let bond = T::PetitionBond::get();
+			T::Currency::reserve(&who, bond)?;
+
+			let proposal_id = Self::new_proposal(
+				group_id,
+				who.clone(),
+				ProposalType::Call,
+				None,
+				title,
+				description,
+				VotingMode::Open,
+				None,
+				true,
+			)?;
+
+			PetitionBonds::<T, I>::insert(proposal_id, (who.clone(), bond));
+			Self::note_call(&who, proposal_id, &call)?;
+
+			Ok(())
+		}
+
+		/// adopt a petition, its voting period starts and the caller approves it first
+		#[pallet::call_index(27)]
+		#[pallet::weight(Weight::from_parts(3_000, 0))]
+		pub fn adopt_petition(origin: OriginFor<T>, proposal_id: u32) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let proposal = Self::proposals(proposal_id).ok_or(Error::<T, I>::NotFoundProposal)?;
+			let group_id = proposal.group;
+			ensure!(
+				MultisigMembers::<T, I>::get(group_id).contains(&who),
+				Error::<T, I>::MustContainCaller
+			);
+			ensure!(proposal.status == ProposalStatus::Petition, Error::<T, I>::NotPetition);
+			Self::ensure_not_frozen(group_id, proposal.proposaltype)?;
+
+			Self::deposit_event(Event::PetitionAdopted { group_id, proposal_id, who: who.clone() });
+
+			Self::open_proposal(proposal_id, proposal, who)
+		}
+
+		/// dismiss a petition, `PetitionForfeit` of its bond is paid to the group account and
+		/// the rest is returned
+		#[pallet::call_index(28)]
+		#[pallet::weight(Weight::from_parts(3_000, 0))]
+		pub fn dismiss_petition(origin: OriginFor<T>, proposal_id: u32) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let proposal = Self::proposals(proposal_id).ok_or(Error::<T, I>::NotFoundProposal)?;
+			let group_id = proposal.group;
+			ensure!(
+				MultisigMembers::<T, I>::get(group_id).contains(&who),
+				Error::<T, I>::MustContainCaller
+			);
+			ensure!(proposal.status == ProposalStatus::Petition, Error::<T, I>::NotPetition);
+
+			let forfeited = Self::forfeit_petition_bond(proposal_id, group_id);
+
+			Self::deposit_event(Event::PetitionDismissed { group_id, proposal_id, who, forfeited });
+
+			Self::finish_a_proposal(proposal_id, proposal, ProposalOutcome::Dismissed);
+
+			Ok(())
+		}
+
+		/// propose the calls the group may dispatch, all of the members must approve it
+		///
+		/// `None` lifts the filter of the group. The filter applies to the outer call of the
+		/// proposals, it is checked when they are created and again when they are executed.
+		#[pallet::call_index(29)]
+		#[pallet::weight(Weight::from_parts(3_000, 0))]
+		pub fn propose_call_filter(
+			origin: OriginFor<T>,
+			group_id: GroupId,
+			filter: Option<Vec<AllowedCall>>,
+			voting_mode: VotingMode,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let proposal_id = Self::create_a_proposal(
+				group_id,
+				who,
+				ProposalType::SetCallFilter,
+				None,
+				Default::default(),
+				None,
+				voting_mode,
+			)?;
+
+			ProposedCallFilters::<T, I>::insert(proposal_id, filter);
+
+			Ok(())
+		}
+	}
+
+	#[pallet::validate_unsigned]
+	impl<T: Config<I>, I: 'static> ValidateUnsigned for Pallet<T, I> {
+		type Call = Call<T, I>;
+
+		// only the votes of member keys which did not vote yet enter the pool
+		fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+			let Call::approve_with_eth {
+				proposal_id,
+				approve,
+				signer,
+				signature,
+				proposal_weight_bound,
+			} = call
+			else {
+				return InvalidTransaction::Call.into()
+			};
+
+			Self::check_eth_vote(*proposal_id, *approve, signer, signature)
+				.map_err(|_| InvalidTransaction::BadProof)?;
+			// a vote executing the proposal would fail on dispatch
+			if Self::execution_weight(*proposal_id).any_gt(*proposal_weight_bound) {
+				return InvalidTransaction::ExhaustsResources.into()
+			}
+
+			let now = frame_system::Pallet::<T>::block_number();
+			let longevity = Self::votings(proposal_id)
+				.map_or(1, |vote| vote.end.saturating_sub(now).saturated_into::<u64>().max(1));
+
+			ValidTransaction::with_tag_prefix("SmultisigEthVote")
+				.priority(T::EthVotePriority::get())
+				.and_provides((T::PalletId::get(), proposal_id, signer))
+				.longevity(longevity)
+				.propagate(true)
+				.build()
+		}
+	}
+
+	impl<T: Config<I>, I: 'static> Pallet<T, I> {
+		/// The account of the multisig group, calls of proposals are dispatched from it.
+		pub fn account_id(group_id: GroupId) -> T::AccountId {
+			T::PalletId::get().into_sub_account_truncating(group_id)
+		}
+
+		/// Deposit the event with the topics of the group accounts, the proposal and the members
+		/// it refers to, see `account_topic` and `proposal_hash`.
+		pub(super) fn deposit_event(event: Event<T, I>) {
+			let topics = event.topics();
+			let event = <T as Config<I>>::RuntimeEvent::from(event);
+
+			frame_system::Pallet::<T>::deposit_event_indexed(&topics, event.into());
+		}
+
+		/// The topic of the events referring to an account, a member or a group account.
+		pub fn account_topic(who: &T::AccountId) -> T::Hash {
+			T::Hashing::hash_of(who)
+		}
+
+		/// The topic of the events referring to the proposal, unique in the instance.
+		pub fn proposal_hash(proposal_id: u32) -> T::Hash {
+			T::Hashing::hash_of(&(T::PalletId::get(), proposal_id))
+		}
+
+		/// The account dispatching the calls of the joint proposals of `groups`, the same for
+		/// any order of the groups.
+		pub fn joint_account_id(mut groups: Vec<GroupId>) -> T::AccountId {
+			groups.sort();
+			groups.dedup();
+
+			let entropy = (b"joint", groups).using_encoded(blake2_256);
+			T::PalletId::get().into_sub_account_truncating(entropy)
+		}
+
+		/// The member account of an Ethereum key, the Ethereum votes are cast for it.
+		pub fn eth_account_id(address: H160) -> T::AccountId {
+			let entropy = (b"eth", address).using_encoded(blake2_256);
+			T::PalletId::get().into_sub_account_truncating(entropy)
+		}
+
+		/// The hash an Ethereum key signs to vote on a revision of the proposal, the signed
+		/// message is `"\x19Ethereum Signed Message:\n32"` followed by the hash.
+		///
+		/// The vote is bound to the chain by its genesis hash and to the payload of the revision.
+		pub fn eth_vote_hash(proposal_id: u32, revision: u32, approve: bool) -> [u8; 32] {
+			let genesis = frame_system::Pallet::<T>::block_hash(BlockNumberFor::<T>::zero());
+			let payload = Self::proposals(proposal_id)
+				.and_then(|proposal| Self::payload_of(proposal_id, proposal.proposaltype))
+				.using_encoded(keccak_256);
+			(b"smultisig", genesis, T::PalletId::get(), proposal_id, revision, payload, approve)
+				.using_encoded(keccak_256)
+		}
+
+		// the member account of the key which signed the vote on the current revision
+		fn check_eth_vote(
+			proposal_id: u32,
+			approve: bool,
+			signer: &H160,
+			signature: &[u8; 65],
+		) -> Result<T::AccountId, DispatchError> {
+			let proposal = Self::proposals(proposal_id).ok_or(Error::<T, I>::NotFoundProposal)?;
+			Self::ensure_open_voting(proposal_id)?;
+			// the checks of `do_vote`, a vote failing on dispatch would stay valid in the pool
+			Self::ensure_not_frozen(proposal.group, proposal.proposaltype)?;
+			ensure!(proposal.status == ProposalStatus::Pending, Error::<T, I>::InvalidVote);
+			ensure!(proposal.tally == TallyMode::HeadCount, Error::<T, I>::TokenWeightedVoting);
+
+			let mut message = b"\x19Ethereum Signed Message:\n32".to_vec();
+			message.extend(Self::eth_vote_hash(proposal_id, proposal.revision, approve));
+			let recovered =
+				sp_io::crypto::secp256k1_ecdsa_recover(signature, &keccak_256(&message))
+					.ok()
+					.map(|public| H160::from_slice(&keccak_256(&public)[12..]));
+			ensure!(recovered == Some(*signer), Error::<T, I>::InvalidEthSignature);
+
+			let who = Self::eth_account_id(*signer);
+			ensure!(Self::is_voter(proposal_id, &who)?, Error::<T, I>::MustContainCaller);
+			ensure!(
+				Self::votings(proposal_id)
+					.is_some_and(|vote| !vote.ayes.contains(&who) && !vote.nays.contains(&who)),
+				Error::<T, I>::RepeatVoting
+			);
+
+			Ok(who)
+		}
+
+		// the account dispatching the call of the proposal
+		fn dispatcher_of(proposal_id: u32, group_id: GroupId) -> T::AccountId {
+			match Self::joint_groups(proposal_id) {
+				Some(groups) => Self::joint_account_id(
+					groups.into_iter().map(|(group_id, _)| group_id).collect(),
+				),
+				None => Self::account_id(group_id),
+			}
+		}
+
+		// the groups voting on the proposal
+		fn voting_groups(proposal_id: u32) -> Result<Vec<GroupId>, DispatchError> {
+			match Self::joint_groups(proposal_id) {
+				Some(groups) => Ok(groups.into_iter().map(|(group_id, _)| group_id).collect()),
+				None => Self::group_of(proposal_id).map(|group_id| vec![group_id]),
+			}
+		}
+
+		// whether the account is a member of a group voting on the proposal
+		fn is_voter(proposal_id: u32, who: &T::AccountId) -> Result<bool, DispatchError> {
+			Ok(Self::voting_groups(proposal_id)?
+				.into_iter()
+				.any(|group_id| MultisigMembers::<T, I>::get(group_id).contains(who)))
+		}
+
+		/// The votes of each group on a joint proposal, a member of several groups is counted
+		/// in each of them.
+		///
+		/// A group needs the approvals of its threshold and at least its dynamic threshold, a
+		/// group without members can not approve the proposal.
+		pub fn joint_tallies(proposal_id: u32) -> Vec<GroupTally> {
+			let vote = Self::votings(proposal_id);
+
+			Self::joint_groups(proposal_id)
+				.unwrap_or_default()
+				.into_iter()
+				.map(|(group_id, threshold)| {
+					let members = MultisigMembers::<T, I>::get(group_id);
+					let count = members.len() as u32;
+					let cast = |voters: Option<&Vec<T::AccountId>>| {
+						voters.map_or(0, |voters| {
+							voters.iter().filter(|voter| members.contains(voter)).count() as u32
+						})
+					};
+
+					GroupTally {
+						group_id,
+						ayes: cast(vote.as_ref().map(|vote| &vote.ayes)),
+						nays: cast(vote.as_ref().map(|vote| &vote.nays)),
+						members: count,
+						threshold: Self::threshold_count(threshold, count)
+							.max(Self::dyn_threshold_of(count))
+							.max(1),
+					}
+				})
+				.collect()
+		}
+
+		/// The identity of the group, with its name as raw bytes.
+		pub fn group_info(
+			group_id: GroupId,
+		) -> Option<GroupInfo<T::AccountId, BlockNumberFor<T>, Vec<u8>>> {
+			Self::groups(group_id).map(|info| GroupInfo {
+				name: info.name.into_inner(),
+				creator: info.creator,
+				created_at: info.created_at,
+			})
+		}
+
+		// the group voting on the proposal
+		fn group_of(proposal_id: u32) -> Result<GroupId, DispatchError> {
+			Self::proposals(proposal_id)
+				.map(|proposal| proposal.group)
+				.ok_or_else(|| Error::<T, I>::NotFoundProposal.into())
+		}
+
+		// generate multisig account
+		pub fn multi_account_id(who: &[T::AccountId], threshold: u16) -> T::AccountId {
+			let entropy = (b"modlpy/utilisuba", who, threshold).using_encoded(blake2_256);
+			Decode::decode(&mut TrailingZeroInput::new(entropy.as_ref()))
+				.expect("infinite length input; no invalid inputs for type; qed")
+		}
+
+		pub fn do_vote(
+			caller: T::AccountId,
+			proposal_id: u32,
+			approve: bool,
+			dynthreshold: u32,
+		) -> Result<bool, DispatchError> {
+			// should be execute the proposal
+			let mut result: bool = false;
+
+			let mut vote = match Self::votings(proposal_id) {
+				Some(vote) => vote,
+				None => return Err(Error::<T, I>::InvalidVote.into()),
+			};
+
+			let mut proposal = match Self::proposals(proposal_id) {
+				Some(proposal) => proposal,
+				None => return Err(Error::<T, I>::NotFoundProposal.into()),
+			};
+
+			Self::ensure_not_frozen(proposal.group, proposal.proposaltype)?;
+			ensure!(proposal.status != ProposalStatus::Draft, Error::<T, I>::DraftProposal);
+			ensure!(proposal.status != ProposalStatus::Petition, Error::<T, I>::PetitionProposal);
+			// an approved proposal waiting for its prerequisites is not voted on anymore
+			ensure!(proposal.status == ProposalStatus::Pending, Error::<T, I>::InvalidVote);
+
+			for group_id in Self::voting_groups(proposal_id)? {
+				if MultisigMembers::<T, I>::get(group_id).contains(&caller) {
+					LastActive::<T, I>::insert(
+						group_id,
+						&caller,
+						frame_system::Pallet::<T>::block_number(),
+					);
+				}
+			}
+
+			let threshold = {
+				let members = Self::counted_members(proposal_id);
+
+				let proposal_threshold = Self::threshold_count(proposal.threshold, members);
+
+				if proposal_threshold > dynthreshold {
+					proposal_threshold
+				} else {
+					dynthreshold
+				}
+			};
+
+			// check if proposal is pending
+			match !vote.ayes.contains(&caller) && !vote.nays.contains(&caller) {
+				false => return Err(Error::<T, I>::RepeatVoting.into()),
+				// secret votes are only counted once the reveal phase is over
+				true if proposal.voting_mode == VotingMode::CommitReveal => {
+					if approve {
+						proposal.vote += 1;
+						vote.ayes.push(caller);
+					} else {
+						vote.nays.push(caller);
+					}
+
+					Voting::<T, I>::insert(proposal_id, vote);
+					Proposals::<T, I>::insert(proposal_id, &proposal);
+				},
+				// each group of a joint proposal must reach its own threshold
+				true if JointGroups::<T, I>::contains_key(proposal_id) => {
+					if approve {
+						proposal.vote += 1;
+						vote.ayes.push(caller.clone());
+					} else {
+						vote.nays.push(caller.clone());
+					}
+
+					Voting::<T, I>::insert(proposal_id, vote);
+					Proposals::<T, I>::insert(proposal_id, &proposal);
+
+					if approve {
+						Self::deposit_event(Event::ApprovalProposal {
+							group_id: proposal.group,
+							proposal_id,
+							who: caller,
+							vote: proposal.vote,
+						});
+					} else {
+						Self::deposit_event(Event::RejectProposal {
+							group_id: proposal.group,
+							proposal_id,
+							who: caller,
+							vote: proposal.vote,
+						});
+					}
+
+					let tallies = Self::joint_tallies(proposal_id);
+					let approved = tallies.iter().all(|tally| tally.ayes >= tally.threshold);
+					let rejected = tallies
+						.iter()
+						.any(|tally| tally.members.saturating_sub(tally.nays) < tally.threshold);
+
+					Self::deposit_event(Event::JointVoteTallied { proposal_id, tallies });
+
+					if approved {
+						result = true;
+					} else if rejected {
+						Self::finish_a_proposal(proposal_id, proposal, ProposalOutcome::Rejected);
+					}
+				},
+				// the frozen balance of the voters is counted instead of their heads
+				true if proposal.tally == TallyMode::TokenWeighted => {
+					let (_, amount) = Self::weighted_votes(proposal_id, &caller)
+						.ok_or(Error::<T, I>::TokenWeightedVoting)?;
+
+					if approve {
+						proposal.vote += 1;
+						vote.ayes.push(caller.clone());
+					} else {
+						vote.nays.push(caller.clone());
+					}
+
+					Voting::<T, I>::insert(proposal_id, vote);
+					Proposals::<T, I>::insert(proposal_id, &proposal);
+
+					let (ayes, nays, total) = Self::weighted_tally(proposal_id);
+					let threshold = Self::weighted_threshold(proposal.threshold, total);
+
+					Self::deposit_event(Event::WeightedVoteCast {
+						group_id: proposal.group,
+						proposal_id,
+						who: caller,
+						approve,
+						amount,
+						ayes,
+						nays,
+						threshold,
+					});
+
+					if ayes >= threshold {
+						result = true;
+					} else if total.saturating_sub(nays) < threshold {
+						Self::finish_a_proposal(proposal_id, proposal, ProposalOutcome::Rejected);
+					}
+				},
+				// the quorum of the group decides once the voting period is over
+				true if Self::quorum_of(&proposal).is_some() => {
+					if approve {
+						proposal.vote += 1;
+						vote.ayes.push(caller.clone());
+					} else {
+						vote.nays.push(caller.clone());
+					}
+
+					Voting::<T, I>::insert(proposal_id, vote);
+					Proposals::<T, I>::insert(proposal_id, &proposal);
+
+					if approve {
+						Self::deposit_event(Event::ApprovalProposal {
+							group_id: proposal.group,
+							proposal_id,
+							who: caller,
+							vote: proposal.vote,
+						});
+					} else {
+						Self::deposit_event(Event::RejectProposal {
+							group_id: proposal.group,
+							proposal_id,
+							who: caller,
+							vote: proposal.vote,
+						});
+					}
+				},
+				true => {
+					// check if the caller approved this proposal
+					if approve {
+						match proposal.vote < threshold {
+							true => {
+								// approve
+								proposal.vote += 1;
+
+								vote.ayes.push(caller.clone());
+
+								if vote.ayes.len() as u32 >= dynthreshold {
+									result = true;
+								}
+
+								Voting::<T, I>::insert(proposal_id, vote);
+								Proposals::<T, I>::insert(proposal_id, &proposal);
+
+								Self::deposit_event(Event::ApprovalProposal {
+									group_id: proposal.group,
+									proposal_id,
+									who: caller,
+									vote: proposal.vote,
+								});
+							},
+							// the approvals already reached the threshold, execute it
+							false => {
+								vote.ayes.push(caller);
+								Voting::<T, I>::insert(proposal_id, vote);
+
+								result = true;
+							},
+						}
+					} else {
+						vote.nays.push(caller.clone());
+						let rejects = vote.nays.len() as u32;
+
+						<Voting<T, I>>::insert(proposal_id, vote);
+
+						let members = Self::counted_members(proposal_id);
+
+						Self::deposit_event(Event::RejectProposal {
+							group_id: proposal.group,
+							proposal_id,
+							who: caller,
+							vote: proposal.vote,
+						});
+
+						// the remaining members can not approve it anymore
+						if members.saturating_sub(rejects) < dynthreshold {
+							Self::finish_a_proposal(
+								proposal_id,
+								proposal,
+								ProposalOutcome::Rejected,
+							);
+						}
+					}
+				},
+			}
+
+			Ok(result)
+		}
+
+		// execute proopsal, returns the weight of the calls dispatched
+		pub fn exe_proposal(proposal_id: u32) -> Result<Weight, DispatchError> {
+			//get proposal status  && proposal vote yes_number > dynthreshold than approve the
+			// proposal such as add member | remove member | transfer etc
+
+			let mut proposal =
+				Self::proposals(proposal_id).ok_or(Error::<T, I>::NotFoundProposal)?;
+
+			// wait until the prerequisites are executed
+			let prerequisites = Self::prerequisites(proposal_id);
+			if !prerequisites.is_empty() {
+				proposal.status = ProposalStatus::AwaitingDependencies;
+				Proposals::<T, I>::insert(proposal_id, proposal);
+
+				Self::deposit_event(Event::AwaitingDependencies {
+					group_id: proposal.group,
+					proposal_id,
+					prerequisites,
+				});
+
+				return Ok(Weight::zero())
+			}
+
+			let (outcome, mut weight) = match Self::do_exe_proposal(proposal_id, &proposal) {
+				Ok((result, weight)) => (ProposalOutcome::Executed(result), weight),
+				Err(e) => (ProposalOutcome::ExecutionFailed(e), Weight::zero()),
+			};
+
+			// the dependents released by the execution
+			weight.saturating_accrue(Self::finish_a_proposal(proposal_id, proposal, outcome));
+
+			Ok(weight)
+		}
+
+		// execute the approved proposal if `bound` covers the calls it dispatches
+		fn execute_within(proposal_id: u32, bound: Weight) -> Result<Weight, DispatchError> {
+			ensure!(
+				Self::execution_weight(proposal_id).all_lte(bound),
+				Error::<T, I>::WrongProposalWeight
+			);
+
+			Self::exe_proposal(proposal_id)
+		}
+
+		/// The weight of the calls dispatched by executing the proposal, its own call and the
+		/// calls of the dependents waiting only for it. Nothing is dispatched while it waits
+		/// for its prerequisites.
+		pub fn execution_weight(proposal_id: u32) -> Weight {
+			if !Self::prerequisites(proposal_id).is_empty() {
+				return Weight::zero()
+			}
+
+			Self::dispatched_weight(proposal_id)
+		}
+
+		// the weight of the call of the proposal and of the approved dependents it releases
+		fn dispatched_weight(proposal_id: u32) -> Weight {
+			Self::dependents(proposal_id)
+				.into_iter()
+				.filter(|dependent| {
+					Self::prerequisites(dependent) == vec![proposal_id] &&
+						Self::proposals(dependent).is_some_and(|proposal| {
+							proposal.status == ProposalStatus::AwaitingDependencies
+						})
+				})
+				.fold(Self::call_weight(proposal_id).unwrap_or_default(), |weight, dependent| {
+					weight.saturating_add(Self::dispatched_weight(dependent))
+				})
+		}
+
+		// apply the proposal, the inner result is the result of the dispatched call, along with
+		// the weight the call used
+		fn do_exe_proposal(
+			proposal_id: u32,
+			proposal: &Proposal<T, I>,
+		) -> Result<(DispatchResult, Weight), DispatchError> {
+			let group_id = proposal.group;
+			for group_id in Self::voting_groups(proposal_id)? {
+				Self::ensure_not_frozen(group_id, proposal.proposaltype)?;
+			}
+
+			match proposal.proposaltype {
+				ProposalType::AddMember => {
+					let member = match Self::add_members(proposal_id) {
+						Some(member) => member,
+						None => return Err(Error::<T, I>::NotFoundAddAccount.into()),
+					};
+
+					let mut members = vec![member];
+
+					Self::do_change_members(group_id, proposal.owner.clone(), &mut members, true)?;
+					// Self::change_multisig_members(&mut members)?;
+				},
+				ProposalType::RemoveMember => {
+					let member = match Self::remove_members(proposal_id) {
+						Some(member) => member,
+						None => return Err(Error::<T, I>::NotFoundRemoveAccount.into()),
+					};
+
+					let mut members = vec![member];
+
+					Self::do_change_members(group_id, proposal.owner.clone(), &mut members, false)?;
+				},
+				ProposalType::Call => {
+					let call =
+						Self::call_proposals(proposal_id).ok_or(Error::<T, I>::NotFoundCall)?;
+					let (call, _) = T::Preimages::peek(&call)?;
+					// the filter may have changed since the proposal was created
+					Self::ensure_call_allowed(proposal_id, &call)?;
+					let info = call.get_dispatch_info();
+
+					let dispatched = call.dispatch(
+						frame_system::RawOrigin::Signed(Self::dispatcher_of(proposal_id, group_id))
+							.into(),
+					);
+					let weight = extract_actual_weight(&dispatched, &info);
+					let result = dispatched.map(|_| ()).map_err(|e| e.error);
+
+					Self::deposit_event(Event::CallExecuted { group_id, proposal_id, result });
+
+					return Ok((result, weight))
+				},
+				ProposalType::SwapMember => {
+					let (old, new) = Self::swap_members(proposal_id)
+						.ok_or(Error::<T, I>::NotFoundSwapAccount)?;
+
+					Self::do_swap_member(group_id, &old, &new)?;
+
+					Self::deposit_event(Event::MemberSwapped { group_id, proposal_id, old, new });
+				},
+				ProposalType::FreezeGroup => {
+					Frozen::<T, I>::insert(group_id, frame_system::Pallet::<T>::block_number());
+
+					Self::deposit_event(Event::GroupFrozen { group_id, proposal_id });
+				},
+				ProposalType::UnfreezeGroup => {
+					Frozen::<T, I>::remove(group_id);
+
+					Self::deposit_event(Event::GroupUnfrozen { group_id, proposal_id });
+				},
+				ProposalType::DissolveGroup => {
+					// nothing is cleared if a transfer fails
+					let funds = with_storage_layer(|| Self::do_dissolve(group_id, proposal_id))?;
+
+					Self::deposit_event(Event::GroupDissolved { group_id, proposal_id, funds });
+				},
+				ProposalType::SetCallFilter => {
+					let filter = Self::proposed_call_filter(proposal_id)
+						.ok_or(Error::<T, I>::NotFoundProposal)?;
+
+					match &filter {
+						Some(filter) => CallFilters::<T, I>::insert(group_id, filter),
+						None => CallFilters::<T, I>::remove(group_id),
+					}
+
+					Self::deposit_event(Event::CallFilterSet { group_id, proposal_id, filter });
+				},
+			}
+
+			Ok((Ok(()), Weight::zero()))
+		}
+
+		// while frozen only the proposals restoring the group can go on
+		fn ensure_not_frozen(group_id: GroupId, proposaltype: ProposalType) -> DispatchResult {
+			ensure!(
+				!Frozen::<T, I>::contains_key(group_id) ||
+					matches!(
+						proposaltype,
+						ProposalType::UnfreezeGroup | ProposalType::SwapMember
+					),
+				Error::<T, I>::GroupFrozen
+			);
+
+			Ok(())
+		}
+
+		// approvals needed to execute the proposal, the emergency threshold for a freeze
+		fn approval_threshold(proposal_id: u32) -> u32 {
+			let members = Self::counted_members(proposal_id);
+
+			match Self::proposals(proposal_id) {
+				Some(proposal) if Self::is_unanimous(proposal.proposaltype) => members,
+				Some(proposal) if proposal.proposaltype == ProposalType::FreezeGroup =>
+					Self::threshold_count(proposal.threshold, members),
+				// the threshold of the type can only raise the threshold of the group
+				Some(proposal) => Self::threshold_count(proposal.threshold, members)
+					.max(Self::dyn_threshold_of(members)),
+				None => Self::dyn_threshold_of(members),
+			}
+		}
+
+		// the members counted in the thresholds of the proposal, the member whose removal was
+		// proposed by the pallet is skipped
+		fn counted_members(proposal_id: u32) -> u32 {
+			let Some(proposal) = Self::proposals(proposal_id) else { return 0 };
+			let members = MultisigMembers::<T, I>::get(proposal.group);
+
+			let skipped = (proposal.owner == Self::account_id(proposal.group))
+				.then(|| Self::remove_members(proposal_id))
+				.flatten()
+				.is_some_and(|member| members.contains(&member));
+
+			(members.len() as u32).saturating_sub(skipped as u32)
+		}
+
+		// open a removal proposal for the members of the group who stopped voting
+		fn propose_inactive_removals(group_id: GroupId, now: BlockNumberFor<T>) -> Weight {
+			let db = T::DbWeight::get();
+			let mut weight = db.reads(2);
+
+			if Frozen::<T, I>::contains_key(group_id) || !Self::can_remove_member(group_id) {
+				return weight
+			}
+
+			for member in MultisigMembers::<T, I>::get(group_id) {
+				weight.saturating_accrue(db.reads(2));
+
+				let last_active = match Self::last_active(group_id, &member) {
+					Some(last_active) => last_active,
+					None => {
+						LastActive::<T, I>::insert(group_id, &member, now);
+						weight.saturating_accrue(db.writes(1));
+						continue
+					},
+				};
+
+				let inactive = now.saturating_sub(last_active) > T::InactivityPeriod::get() ||
+					Self::missed_proposals(group_id, &member) >= T::MaxMissedProposals::get();
+				if !inactive || Self::removal_pending(group_id, &member) {
+					continue
+				}
+
+				// the group account owns the proposals opened by the pallet
+				let opened = with_storage_layer(|| {
+					Self::create_a_proposal(
+						group_id,
+						Self::account_id(group_id),
+						ProposalType::RemoveMember,
+						Some(member.clone()),
+						Default::default(),
+						None,
+						VotingMode::Open,
+					)
+				});
+				weight.saturating_accrue(db.reads_writes(4, 6));
+
+				if let Ok(proposal_id) = opened {
+					// a new period starts, whatever the members decide
+					LastActive::<T, I>::insert(group_id, &member, now);
+					MissedProposals::<T, I>::remove(group_id, &member);
+
+					Self::deposit_event(Event::InactiveMember { group_id, member, proposal_id });
+				}
+			}
+
+			weight
+		}
+
+		// check the groups after `InactivityCursor` for inactive members, as many as `limit`
+		// covers, and start over once every group was checked
+		fn check_inactivity(now: BlockNumberFor<T>, limit: Weight) -> Weight {
+			let db = T::DbWeight::get();
+			let mut consumed = db.reads(1);
+			// the members of a group open a removal proposal each at most
+			let per_group = db.reads_writes(3, 1).saturating_add(
+				db.reads_writes(6, 8).saturating_mul(T::MaxMultisigNumber::get().into()),
+			);
+			if consumed.saturating_add(per_group).any_gt(limit) {
+				return Weight::zero()
+			}
+
+			let groups = match Self::inactivity_cursor() {
+				Some(last) => MultisigMembers::<T, I>::iter_keys_from(
+					MultisigMembers::<T, I>::hashed_key_for(last),
+				),
+				None => MultisigMembers::<T, I>::iter_keys(),
+			};
+
+			let mut cursor = None;
+			for group_id in groups {
+				if consumed.saturating_add(per_group).any_gt(limit) {
+					// the next check resumes after the last group checked
+					InactivityCursor::<T, I>::set(cursor);
+					return consumed.saturating_add(db.writes(1))
+				}
+				consumed.saturating_accrue(Self::propose_inactive_removals(group_id, now));
+				consumed.saturating_accrue(db.reads(1));
+				cursor = Some(group_id);
+			}
+
+			InactivityCursor::<T, I>::kill();
+			consumed.saturating_accrue(db.writes(1));
+
+			consumed
+		}
+
+		fn removal_pending(group_id: GroupId, member: &T::AccountId) -> bool {
+			PendingRemovals::<T, I>::contains_key(group_id, member)
+		}
+
+		// count a pending proposal removing `member`, or drop it once the proposal is finished
+		fn count_removal(group_id: GroupId, member: &T::AccountId, pending: bool) {
+			PendingRemovals::<T, I>::mutate_exists(group_id, member, |count| {
+				*count = match pending {
+					true => Some(count.unwrap_or_default().saturating_add(1)),
+					false => count.map(|count| count.saturating_sub(1)).filter(|count| *count > 0),
+				};
+			});
+		}
+
+		// count the members of the group who did not vote on a decided proposal
+		fn record_participation(group_id: GroupId, proposal_id: u32) {
+			let vote = Self::votings(proposal_id);
+
+			for member in MultisigMembers::<T, I>::get(group_id) {
+				let voted = Commitments::<T, I>::contains_key(proposal_id, &member) ||
+					vote.as_ref().is_some_and(|vote| {
+						vote.ayes.contains(&member) || vote.nays.contains(&member)
+					});
+
+				if voted {
+					MissedProposals::<T, I>::remove(group_id, &member);
+				} else {
+					MissedProposals::<T, I>::mutate(group_id, &member, |missed| *missed += 1);
+				}
+			}
+		}
+
+		// the primary group is created along with its first members with a fresh id, owned by
+		// its own account, so that it never takes over a group created by the users
+		pub(crate) fn ensure_primary_group() -> GroupId {
+			if let Some(group_id) = Self::primary_group() {
+				return group_id
+			}
+
+			let group_id = Self::next_group_id();
+			Groups::<T, I>::insert(
+				group_id,
+				GroupInfo {
+					name: Default::default(),
+					creator: Self::account_id(group_id),
+					created_at: frame_system::Pallet::<T>::block_number(),
+				},
+			);
+			PrimaryGroup::<T, I>::put(group_id);
+			group_id
+		}
+
+		// the members of the group of the membership traits
+		fn primary_members() -> Vec<T::AccountId> {
+			Self::primary_group()
+				.map(|group_id| Self::members(group_id).to_vec())
+				.unwrap_or_default()
+		}
+
+		// new members start active and are indexed, the activity and the index entry of the
+		// leaving members are dropped
+		pub(crate) fn track_members(
+			group_id: GroupId,
+			incoming: &[T::AccountId],
+			outgoing: &[T::AccountId],
+		) {
+			let now = frame_system::Pallet::<T>::block_number();
+
+			for member in incoming {
+				LastActive::<T, I>::insert(group_id, member, now);
+				MemberGroups::<T, I>::mutate(member, |groups| {
+					if !groups.contains(&group_id) {
+						groups.push(group_id);
+					}
+				});
+			}
+			for member in outgoing {
+				LastActive::<T, I>::remove(group_id, member);
+				MissedProposals::<T, I>::remove(group_id, member);
+
+				let mut groups = Self::groups_of(member);
+				groups.retain(|id| *id != group_id);
+				if groups.is_empty() {
+					MemberGroups::<T, I>::remove(member);
+				} else {
+					MemberGroups::<T, I>::insert(member, groups);
+				}
+			}
+		}
+
+		// the membership hook follows the primary group
+		fn notify_members_changed(
+			group_id: GroupId,
+			incoming: &[T::AccountId],
+			outgoing: &[T::AccountId],
+		) {
+			if Self::primary_group() == Some(group_id) {
+				T::MembershipChanged::change_members_sorted(
+					incoming,
+					outgoing,
+					&Self::members(group_id),
+				);
+			}
+		}
+
+		// the balance approving and rejecting the proposal, and the balance of all of the members
+		fn weighted_tally(proposal_id: u32) -> (BalanceOf<T, I>, BalanceOf<T, I>, BalanceOf<T, I>) {
+			let members = Self::group_of(proposal_id)
+				.map(MultisigMembers::<T, I>::get)
+				.unwrap_or_default();
+
+			let mut ayes = BalanceOf::<T, I>::zero();
+			let mut nays = BalanceOf::<T, I>::zero();
+			for (who, (approve, amount)) in WeightedVotes::<T, I>::iter_prefix(proposal_id) {
+				if !members.contains(&who) {
+					continue
+				}
+				if approve {
+					ayes.saturating_accrue(amount);
+				} else {
+					nays.saturating_accrue(amount);
+				}
+			}
+			let total = members.iter().fold(BalanceOf::<T, I>::zero(), |total, member| {
+				total.saturating_add(T::Currency::total_balance(member))
+			});
+
+			(ayes, nays, total)
+		}
+
+		// the part of `total` a weighted proposal needs, as `threshold_count` for the heads
+		fn weighted_threshold(
+			threshold: ProposalThreshold,
+			total: BalanceOf<T, I>,
+		) -> BalanceOf<T, I> {
+			let part = |numerator: u32, denominator: u32| {
+				(total / denominator.into())
+					.saturating_mul(numerator.into())
+					.saturating_add(One::one())
+			};
+
+			match threshold {
+				ProposalThreshold::All => total,
+				ProposalThreshold::MoreThanTwoThirds => part(2, 3),
+				ProposalThreshold::MoreThanhalf => part(1, 2),
+				ProposalThreshold::MoreThanThreeQuarters => part(3, 4),
+			}
+		}
+
+		// drop the weighted votes on the proposal and lower the freezes of their voters
+		fn release_weighted_votes(proposal_id: u32) {
+			let voters = WeightedVotes::<T, I>::drain_prefix(proposal_id)
+				.map(|(who, _)| who)
+				.collect::<Vec<_>>();
+
+			for who in voters {
+				Self::update_voting_freeze(&who);
+			}
+		}
+
+		// lower the freeze of the voter to its largest weighted vote on a pending proposal
+		fn update_voting_freeze(who: &T::AccountId) {
+			let locked = Proposals::<T, I>::iter_keys()
+				.filter_map(|pending| Self::weighted_votes(pending, who))
+				.map(|(_, amount)| amount)
+				.max();
+
+			let _ = match locked {
+				Some(amount) => T::Freezer::set_freeze(&T::FreezeId::get(), who, amount),
+				None => T::Freezer::thaw(&T::FreezeId::get(), who),
+			};
+		}
+
+		fn threshold_count(threshold: ProposalThreshold, members: u32) -> u32 {
+			match threshold {
+				ProposalThreshold::All => members,
+				ProposalThreshold::MoreThanTwoThirds => 2 * members / 3 + 1,
+				ProposalThreshold::MoreThanhalf => (members / 2) + 1,
+				ProposalThreshold::MoreThanThreeQuarters => 3 * members / 4 + 1,
+			}
+		}
+
+		fn ensure_open_voting(proposal_id: u32) -> DispatchResult {
+			if let Some(proposal) = Self::proposals(proposal_id) {
+				ensure!(
+					proposal.voting_mode == VotingMode::Open,
+					Error::<T, I>::CommitRevealVoting
+				);
+			}
+
+			Ok(())
+		}
+
+		fn commit_end_of(proposal_id: u32) -> Result<BlockNumberFor<T>, DispatchError> {
+			ensure!(Proposals::<T, I>::contains_key(proposal_id), Error::<T, I>::NotFoundProposal);
+
+			Self::votings(proposal_id)
+				.and_then(|vote| vote.commit_end)
+				.ok_or_else(|| Error::<T, I>::NotCommitRevealVoting.into())
+		}
+
+		// count the revealed votes, unrevealed votes are abstentions, returns the weight of the
+		// calls executed
+		fn close_commit_reveal(proposal_id: u32, proposal: Proposal<T, I>) -> Weight {
+			let ayes = Self::votings(proposal_id).map_or(0, |vote| vote.ayes.len() as u32);
+			let dyn_threshold = Self::approval_threshold(proposal_id);
+
+			if ayes >= dyn_threshold {
+				Self::exe_proposal(proposal_id).unwrap_or_default()
+			} else {
+				Self::finish_a_proposal(proposal_id, proposal, ProposalOutcome::Rejected)
+			}
+		}
+
+		// decide the proposal from the turnout and the approval ratio of the votes cast, returns
+		// the weight of the calls executed
+		fn close_with_quorum(
+			proposal_id: u32,
+			proposal: Proposal<T, I>,
+			quorum: QuorumConfig,
+		) -> Weight {
+			let (ayes, nays) = Self::votings(proposal_id)
+				.map_or((0, 0), |vote| (vote.ayes.len() as u32, vote.nays.len() as u32));
+			let cast = ayes.saturating_add(nays);
+			let members = Self::counted_members(proposal_id);
+
+			let reached = cast > 0 && cast >= quorum.turnout.mul_ceil(members);
+			let approved = ayes > 0 && ayes >= quorum.approval.mul_ceil(cast);
+
+			let outcome = match (reached, approved) {
+				// the threshold of the proposal holds whatever the turnout
+				(true, true) if ayes >= Self::approval_threshold(proposal_id) =>
+					return Self::exe_proposal(proposal_id).unwrap_or_default(),
+				(true, _) => ProposalOutcome::Rejected,
+				(false, true) => ProposalOutcome::FailedQuorum,
+				(false, false) => ProposalOutcome::Expired,
+			};
+
+			Self::finish_a_proposal(proposal_id, proposal, outcome)
+		}
+
+		pub(crate) fn remove_delegations(group_id: GroupId, member: &T::AccountId) {
+			let delegators = Delegations::<T, I>::iter_prefix(group_id)
+				.filter(|(delegator, (delegate, _))| delegator == member || delegate == member)
+				.map(|(delegator, _)| delegator)
+				.collect::<Vec<_>>();
+
+			for who in delegators {
+				Delegations::<T, I>::remove(group_id, &who);
+				Self::deposit_event(Event::DelegationRemoved { group_id, who });
+			}
+		}
+
+		fn next_group_id() -> GroupId {
+			GroupCount::<T, I>::mutate(|count| {
+				let group_id = *count;
+				*count = count.saturating_add(1);
+				group_id
+			})
+		}
+
+		fn next_proposal_id() -> u32 {
+			ProposalCount::<T, I>::mutate(|count| {
+				*count = count.saturating_add(1);
+				*count
+			})
+		}
+
+		// move a proposal into the finished history with its outcome, returns the weight of the
+		// calls of the dependents it executed
+		fn finish_a_proposal(
+			proposal_id: u32,
+			mut proposal: Proposal<T, I>,
+			outcome: ProposalOutcome,
+		) -> Weight {
+			let voted =
+				!matches!(proposal.status, ProposalStatus::Petition | ProposalStatus::Draft);
+			proposal.status = ProposalStatus::Finished;
+			let group_id = proposal.group;
+
+			Self::deposit_event(Event::FinishedProposal {
+				group_id,
+				proposal_id,
+				vote: proposal.vote,
+				outcome: outcome.clone(),
+			});
+
+			// a petition which was not adopted or a draft which was not opened was never voted on
+			if voted &&
+				matches!(
+					outcome,
+					ProposalOutcome::Executed(_) |
+						ProposalOutcome::ExecutionFailed(_) |
+						ProposalOutcome::Rejected |
+						ProposalOutcome::Expired |
+						ProposalOutcome::FailedQuorum
+				) {
+				Self::record_participation(group_id, proposal_id);
+			}
+
+			Self::clear_call(proposal_id);
+			// the stored owner, a swap may have replaced it meanwhile
+			let stored = Proposals::<T, I>::take(proposal_id);
+			if stored.is_some() {
+				GroupProposals::<T, I>::mutate_exists(group_id, |count| {
+					*count = count.map(|count| count.saturating_sub(1)).filter(|count| *count > 0);
+				});
+			}
+			let owner = stored.map_or_else(|| proposal.owner.clone(), |stored| stored.owner);
+			MemberProposals::<T, I>::mutate_exists(group_id, owner, |count| {
+				*count = count.map(|count| count.saturating_sub(1)).filter(|count| *count > 0);
+			});
+			if let Some(member) = Self::remove_members(proposal_id) {
+				Self::count_removal(group_id, &member, false);
+			}
+			Prerequisites::<T, I>::remove(proposal_id);
+			Sponsors::<T, I>::remove(proposal_id);
+			let _ = Commitments::<T, I>::clear_prefix(proposal_id, u32::MAX, None);
+			Self::release_weighted_votes(proposal_id);
+
+			let executed = outcome == ProposalOutcome::Executed(Ok(()));
+			let record = ProposalRecord {
+				proposal,
+				outcome,
+				finished_at: frame_system::Pallet::<T>::block_number(),
+			};
+			FinishedProposal::<T, I>::insert(proposal_id, record);
+			FinishedQueue::<T, I>::append(proposal_id);
+
+			Self::resolve_dependents(proposal_id, executed)
+		}
+
+		// the prerequisites which are still pending, executed ones are already satisfied, they
+		// must be proposals of the group
+		fn outstanding_prerequisites(
+			group_id: GroupId,
+			mut prerequisites: Vec<u32>,
+		) -> Result<Vec<u32>, DispatchError> {
+			prerequisites.sort();
+			prerequisites.dedup();
+
+			for proposal_id in &prerequisites {
+				let executed = Self::finished_proposal(proposal_id).is_some_and(|record| {
+					record.proposal.group == group_id &&
+						record.outcome == ProposalOutcome::Executed(Ok(()))
+				});
+				let pending =
+					Self::proposals(proposal_id).is_some_and(|proposal| proposal.group == group_id);
+
+				ensure!(executed || pending, Error::<T, I>::InvalidPrerequisite);
+			}
+			prerequisites.retain(|proposal_id| Proposals::<T, I>::contains_key(proposal_id));
+
+			Ok(prerequisites)
+		}
+
+		// release or fail the proposals waiting for a finished prerequisite, returns the weight of
+		// the calls executed
+		fn resolve_dependents(proposal_id: u32, executed: bool) -> Weight {
+			let mut weight = Weight::zero();
+			for dependent in Dependents::<T, I>::take(proposal_id) {
+				// the dependent may already be finished
+				let Some(proposal) = Self::proposals(dependent) else { continue };
+
+				if !executed {
+					weight.saturating_accrue(Self::finish_a_proposal(
+						dependent,
+						proposal,
+						ProposalOutcome::DependencyFailed(proposal_id),
+					));
+					continue
+				}
+
+				let mut prerequisites = Self::prerequisites(dependent);
+				prerequisites.retain(|prerequisite| *prerequisite != proposal_id);
+				if !prerequisites.is_empty() {
+					Prerequisites::<T, I>::insert(dependent, prerequisites);
+					continue
+				}
+
+				Prerequisites::<T, I>::remove(dependent);
+				if proposal.status == ProposalStatus::AwaitingDependencies {
+					weight.saturating_accrue(Self::exe_proposal(dependent).unwrap_or_default());
+				}
+			}
+
+			weight
+		}
+
+		// remove the oldest records over `MaxFinishedProposals`, within `limit`
+		fn prune_finished_proposals(limit: Weight) -> Weight {
+			let db = T::DbWeight::get();
+			let mut consumed = db.reads(1);
+			if consumed.any_gt(limit) {
+				return Weight::zero()
+			}
+
+			let mut queue = FinishedQueue::<T, I>::get();
+			let max = T::MaxFinishedProposals::get() as usize;
+			let per_record = db.writes(7);
+
+			let mut pruned = 0;
+			while queue.len() - pruned > max &&
+				consumed.saturating_add(per_record).saturating_add(db.writes(1)).all_lte(limit)
+			{
+				Self::remove_record(queue[pruned]);
+
+				consumed.saturating_accrue(per_record);
+				pruned += 1;
+			}
 
-			// check the sender  in multisig group
-			match MultisigMembers::<T>::get().contains(&who) &&
-				MultisigMembers::<T>::get().contains(&member)
-			{
-				true => {
-					// just create remove member proposal
-					Self::create_a_proposal(who, 1, 2, false, member)?;
-				},
+			if pruned > 0 {
+				queue.drain(..pruned);
+				FinishedQueue::<T, I>::put(queue);
+				consumed.saturating_accrue(db.writes(1));
 
-				false => return Err(Error::<T>::NotFoundAccount.into()),
+				Self::deposit_event(Event::PrunedProposals { count: pruned as u32 });
 			}
 
-			//todo ! check if member exists
-			Ok(())
+			consumed
 		}
 
-		#[pallet::call_index(5)]
-		#[pallet::weight(Weight::from_parts(3_000, 0))]
-		pub fn add_member(origin: OriginFor<T>, member: T::AccountId) -> DispatchResult {
-			let who = ensure_signed(origin.clone())?;
+		// remove a finished proposal from the history
+		fn remove_record(proposal_id: u32) {
+			FinishedProposal::<T, I>::remove(proposal_id);
+			Voting::<T, I>::remove(proposal_id);
+			AddMember::<T, I>::remove(proposal_id);
+			RemoveMember::<T, I>::remove(proposal_id);
+			SwapMember::<T, I>::remove(proposal_id);
+			Self::clear_revisions(proposal_id);
+			DissolveBeneficiaries::<T, I>::remove(proposal_id);
+			JointGroups::<T, I>::remove(proposal_id);
+			ProposedCallFilters::<T, I>::remove(proposal_id);
+		}
 
-			// check the sender  in multisig group
-			match MultisigMembers::<T>::get().contains(&who) &&
-				!MultisigMembers::<T>::get().contains(&member)
-			{
-				true => {
-					// just create add member proposal
-					Self::create_a_proposal(who, 3, 1, true, member)?;
+		// the end of the voting period and of the commit phase of a proposal opened at `now`
+		fn voting_deadlines(
+			voting_mode: VotingMode,
+			now: BlockNumberFor<T>,
+		) -> (BlockNumberFor<T>, Option<BlockNumberFor<T>>) {
+			match voting_mode {
+				VotingMode::Open => (now.saturating_add(T::VotingPeriod::get()), None),
+				VotingMode::CommitReveal => {
+					let commit_end = now.saturating_add(T::VotingPeriod::get());
+					(commit_end.saturating_add(T::RevealPeriod::get()), Some(commit_end))
 				},
-				false => return Err(Error::<T>::NotFoundAccount.into()),
 			}
-
-			//todo ! check if member exists
-			Ok(())
-		}
-	}
-
-	impl<T: Config> Pallet<T> {
-		// generate multisig account
-		pub fn multi_account_id(who: &[T::AccountId], threshold: u16) -> T::AccountId {
-			let entropy = (b"modlpy/utilisuba", who, threshold).using_encoded(blake2_256);
-			Decode::decode(&mut TrailingZeroInput::new(entropy.as_ref()))
-				.expect("infinite length input; no invalid inputs for type; qed")
 		}
 
-		pub fn do_vote(
-			caller: T::AccountId,
+		// start the voting period of a sponsored draft or an adopted petition, `voter` votes
+		// first as the owner on creation
+		fn open_proposal(
 			proposal_id: u32,
-			approve: bool,
-			dynthreshold: u32,
-		) -> Result<bool, DispatchError> {
-			// should be execute the proposal
-			let mut result: bool = false;
-
-			let mut vote = match Self::votings(proposal_id) {
-				Some(vote) => vote,
-				None => return Err(Error::<T>::InvalidVote.into()),
-			};
+			mut proposal: Proposal<T, I>,
+			voter: T::AccountId,
+		) -> DispatchResult {
+			let mut vote = Self::votings(proposal_id).ok_or(Error::<T, I>::InvalidVote)?;
+			let (end, commit_end) = Self::voting_deadlines(
+				proposal.voting_mode,
+				frame_system::Pallet::<T>::block_number(),
+			);
+			vote.end = end;
+			vote.commit_end = commit_end;
+			Voting::<T, I>::insert(proposal_id, &vote);
 
-			let mut proposal = match Self::proposals(proposal_id) {
-				Some(proposal) => proposal,
-				None => return Err(Error::<T>::NotFoundProposal.into()),
-			};
+			proposal.status = ProposalStatus::Pending;
+			Proposals::<T, I>::insert(proposal_id, &proposal);
 
-			let threshold = {
-				let members = Self::members().len() as u32;
+			Self::deposit_event(Event::ProposalOpened {
+				group_id: proposal.group,
+				proposal_id,
+				end,
+			});
 
-				let proposal_threshold = match proposal.threshold {
-					ProposalThreshold::All => members,
-					ProposalThreshold::MoreThanTwoThirds => 2 * (members % 3) + 1,
-					ProposalThreshold::MoreThanhalf => (members / 2) + 1,
-					ProposalThreshold::MoreThanThreeQuarters => 3 * (members % 4) + 1,
-				};
+			if proposal.voting_mode == VotingMode::Open && proposal.tally == TallyMode::HeadCount {
+				let dynthreshold = Self::approval_threshold(proposal_id);
+				Self::do_vote(voter, proposal_id, true, dynthreshold)?;
+			}
 
-				if proposal_threshold > dynthreshold {
-					proposal_threshold
-				} else {
-					dynthreshold
-				}
-			};
+			Ok(())
+		}
 
-			// check if proposal is pending
-			match !vote.ayes.contains(&caller) && !vote.nays.contains(&caller) {
-				false => return Err(Error::<T>::RepeatVoting.into()),
-				true => {
-					// check if proposal is pending and approved this proposal
-					if proposal.status == ProposalStatus::Pending && approve {
-						match proposal.vote < threshold {
-							true => {
-								// approve
-								proposal.vote += 1;
+		// note the call of a proposal with its weight and reserve the deposit of its owner
+		fn note_call(
+			who: &T::AccountId,
+			proposal_id: u32,
+			call: &<T as Config<I>>::RuntimeCall,
+		) -> DispatchResult {
+			Self::ensure_call_allowed(proposal_id, call)?;
 
-								vote.ayes.push(caller.clone());
+			let encoded_call = call.encode();
+			let len = encoded_call.len() as u32;
 
-								if vote.ayes.len() as u32 >= dynthreshold {
-									result = true;
-								}
+			let deposit = T::ProposalDepositBase::get()
+				.saturating_add(T::ProposalDepositPerByte::get().saturating_mul(len.into()));
+			T::Currency::reserve(who, deposit)?;
 
-								Voting::<T>::insert(proposal_id, vote);
+			// the proposals sharing a call request its preimage once each, noting it requests it
+			// unless it was already requested
+			let requested = T::Preimages::is_requested(&T::Hashing::hash(&encoded_call));
+			let hash = T::Preimages::note(encoded_call.into())?;
+			if requested {
+				T::Preimages::request(&hash);
+			}
 
-								Self::deposit_event(Event::ApprovalProposal {
-									proposal_id,
-									who: caller,
-									vote: proposal.vote,
-								});
-							},
-							false => {
-								proposal.status = ProposalStatus::Finished;
-								Self::deposit_event(Event::FinshedProposal {
-									proposal_id,
-									vote: proposal.vote,
-								});
-								Proposals::<T>::remove(proposal_id);
-								FinishedProposal::<T>::insert(proposal_id, proposal);
-							},
-						}
-					} else if proposal.status == ProposalStatus::Pending && !approve {
-						vote.nays.push(caller.clone());
-						let rejects = vote.nays.len() as u32;
+			CallProposal::<T, I>::insert(proposal_id, BoundedCallOf::<T, I>::Lookup { hash, len });
+			CallWeights::<T, I>::insert(proposal_id, call.get_dispatch_info().weight);
+			ProposalDeposit::<T, I>::insert(proposal_id, (who.clone(), deposit));
 
-						<Voting<T>>::insert(proposal_id, vote);
+			Ok(())
+		}
 
-						let members = Self::members().len() as u32;
+		// drop the replaced revisions and release their calls
+		fn clear_revisions(proposal_id: u32) {
+			for revision in Revisions::<T, I>::take(proposal_id) {
+				if let ProposalPayload::Call(call) = revision.payload {
+					T::Preimages::unrequest(&call.hash());
+				}
+			}
+		}
 
-						match members - dynthreshold <= rejects {
-							true => {
-								proposal.status = ProposalStatus::Finished;
-								Self::deposit_event(Event::FinshedProposal {
-									proposal_id,
-									vote: proposal.vote,
-								});
-								Proposals::<T>::remove(proposal_id);
-								FinishedProposal::<T>::insert(proposal_id, &proposal);
-							},
-							false => {},
-						}
+		/// Whether the call filter of the group allows `call`, any call is allowed while the
+		/// group has no filter.
+		pub fn is_call_allowed(group_id: GroupId, call: &<T as Config<I>>::RuntimeCall) -> bool {
+			let Some(filter) = Self::call_filter(group_id) else { return true };
 
-						Self::deposit_event(Event::RejectProposal {
-							proposal_id,
-							who: caller,
-							vote: proposal.vote,
-						});
-					}
-				},
-			}
+			// the encoded call starts with the index of its pallet and of the call
+			let (pallet, index) =
+				call.using_encoded(|bytes| (bytes.first().copied(), bytes.get(1).copied()));
 
-			Ok(result)
+			filter.iter().any(|allowed| {
+				Some(allowed.pallet) == pallet && (allowed.call.is_none() || allowed.call == index)
+			})
 		}
 
-		// execute proopsal
-		pub fn exe_proposal(proposal_id: u32) -> DispatchResult {
-			//get proposal status  && proposal vote yes_number > dynthreshold than approve the
-			// proposal such as add member | remove member | transfer etc
+		// every group voting on the proposal must allow its call
+		fn ensure_call_allowed(
+			proposal_id: u32,
+			call: &<T as Config<I>>::RuntimeCall,
+		) -> DispatchResult {
+			for group_id in Self::voting_groups(proposal_id)? {
+				ensure!(Self::is_call_allowed(group_id, call), Error::<T, I>::CallNotAllowed);
+			}
 
-			let mut proposal = Self::proposals(proposal_id).ok_or(Error::<T>::NotFoundProposal)?;
+			Ok(())
+		}
 
-			proposal.status = ProposalStatus::Finished;
+		// the current payload of a proposal, `None` for the types without one
+		fn payload_of(
+			proposal_id: u32,
+			proposaltype: ProposalType,
+		) -> Option<ProposalPayload<T::AccountId, BoundedCallOf<T, I>>> {
+			match proposaltype {
+				ProposalType::AddMember =>
+					Self::add_members(proposal_id).map(ProposalPayload::Member),
+				ProposalType::RemoveMember =>
+					Self::remove_members(proposal_id).map(ProposalPayload::Member),
+				ProposalType::SwapMember => Self::swap_members(proposal_id)
+					.map(|(old, new)| ProposalPayload::Swap(old, new)),
+				ProposalType::Call => Self::call_proposals(proposal_id).map(ProposalPayload::Call),
+				ProposalType::FreezeGroup |
+				ProposalType::UnfreezeGroup |
+				ProposalType::DissolveGroup |
+				ProposalType::SetCallFilter => None,
+			}
+		}
 
-			match proposal.proposaltype {
-				ProposalType::AddMember => {
-					let member = match Self::add_members(proposal_id) {
-						Some(member) => member,
-						None => return Err(Error::<T>::NotFoundAddAccount.into()),
-					};
+		// release the call preimage and return the deposit once a proposal is finished, the
+		// preimage is dropped with its last request
+		fn clear_call(proposal_id: u32) {
+			if let Some(call) = CallProposal::<T, I>::take(proposal_id) {
+				T::Preimages::unrequest(&call.hash());
+			}
+			CallWeights::<T, I>::remove(proposal_id);
 
-					let mut members = vec![member];
+			if let Some((who, deposit)) = ProposalDeposit::<T, I>::take(proposal_id) {
+				T::Currency::unreserve(&who, deposit);
+			}
 
-					Self::do_change_members(proposal.owner, &mut members, true);
-					// Self::change_multisig_members(&mut members)?;
-				},
-				ProposalType::RemoveMember => {
-					let member = match Self::remove_members(proposal_id) {
-						Some(member) => member,
-						None => return Err(Error::<T>::NotFoundRemoveAccount.into()),
-					};
+			if let Some((who, bond)) = PetitionBonds::<T, I>::take(proposal_id) {
+				T::Currency::unreserve(&who, bond);
+			}
+		}
 
-					let mut members = vec![member];
+		// pay `PetitionForfeit` of the bond of a petition to the group account, the rest is
+		// returned
+		fn forfeit_petition_bond(proposal_id: u32, group_id: GroupId) -> BalanceOf<T, I> {
+			let Some((owner, bond)) = PetitionBonds::<T, I>::take(proposal_id) else {
+				return Zero::zero()
+			};
 
-					Self::do_change_members(proposal.owner, &mut members, false);
-				},
-			}
+			let (slashed, _) =
+				T::Currency::slash_reserved(&owner, T::PetitionForfeit::get() * bond);
+			let forfeited = slashed.peek();
+			T::Currency::resolve_creating(&Self::account_id(group_id), slashed);
+			T::Currency::unreserve(&owner, bond.saturating_sub(forfeited));
 
-			Ok(())
+			forfeited
 		}
 
 		// create a proposal by user behavior
 		pub fn create_a_proposal(
+			group_id: GroupId,
 			caller: T::AccountId,
-			threshold_u32: u32,
-			proposaltype: u32,
-			signal: bool,
-			change_member: T::AccountId,
-		) -> DispatchResult {
+			proposaltype: ProposalType,
+			change_member: Option<T::AccountId>,
+			title: BoundedVec<u8, T::MaxTitleLength>,
+			description: Option<T::Hash>,
+			voting_mode: VotingMode,
+		) -> Result<u32, DispatchError> {
+			Self::new_proposal(
+				group_id,
+				caller,
+				proposaltype,
+				change_member,
+				title,
+				description,
+				voting_mode,
+				None,
+				false,
+			)
+		}
+
+		// create a proposal with the `threshold` given or the one of its type, a `petition` of a
+		// non-member waits to be adopted by a member
+		#[allow(clippy::too_many_arguments)]
+		fn new_proposal(
+			group_id: GroupId,
+			caller: T::AccountId,
+			proposaltype: ProposalType,
+			change_member: Option<T::AccountId>,
+			title: BoundedVec<u8, T::MaxTitleLength>,
+			description: Option<T::Hash>,
+			voting_mode: VotingMode,
+			threshold: Option<ProposalThreshold>,
+			petition: bool,
+		) -> Result<u32, DispatchError> {
+			// the proposals of the group account are opened by the pallet, without a first vote
+			let by_group = caller == Self::account_id(group_id);
+			let members = MultisigMembers::<T, I>::get(group_id);
+
 			// does account contain the multisig group?
-			match MultisigMembers::<T>::get().contains(&caller) {
+			match by_group || petition || members.contains(&caller) {
 				true => {
-					let multisig_members = MultisigMembers::<T>::get();
-					let multisig_members_len = multisig_members.len();
-
-					if multisig_members_len > 5 {
-						return Err(Error::<T>::MaxProposalNumber.into())
+					if Self::group_proposals(group_id) >= T::MaxProposalNumber::get() {
+						Err(Error::<T, I>::MaxProposalNumber.into())
 					} else {
-						let proposal_id = Proposals::<T>::iter().count() as u32 + 1;
+						if !by_group && !petition {
+							MemberProposals::<T, I>::try_mutate(
+								group_id,
+								&caller,
+								|count| -> DispatchResult {
+									ensure!(
+										*count < T::MaxMemberProposalNumber::get(),
+										Error::<T, I>::MaxMemberProposalNumber
+									);
+									*count += 1;
+									Ok(())
+								},
+							)?;
+						}
+
+						Self::ensure_not_frozen(group_id, proposaltype)?;
+
+						// the frozen balance is only counted on open votes
+						let tally = Self::tally_mode(group_id);
+						ensure!(
+							tally == TallyMode::HeadCount || voting_mode == VotingMode::Open,
+							Error::<T, I>::TokenWeightedVoting
+						);
+
+						let proposal_id = Self::next_proposal_id();
+						let threshold =
+							threshold.unwrap_or_else(|| Self::threshold_of(group_id, proposaltype));
+
+						let (end, commit_end) = Self::voting_deadlines(
+							voting_mode,
+							frame_system::Pallet::<T>::block_number(),
+						);
 
 						let vote: Votes<T> = Votes {
 							index: proposal_id,
-							threshold: threshold_u32,
+							threshold: Self::threshold_count(threshold, members.len() as u32),
 							ayes: Vec::new(),
 							nays: Vec::new(),
+							end,
+							commit_end,
+							delegations: Vec::new(),
 						};
 
-						Voting::<T>::insert(proposal_id, &vote);
+						Voting::<T, I>::insert(proposal_id, &vote);
 
-						let threshold = match threshold_u32 {
-							1..=3 => ProposalThreshold::All,
-							6 => ProposalThreshold::MoreThanhalf,
-							_ => ProposalThreshold::MoreThanTwoThirds,
-						};
-
-						let protype = match proposaltype {
-							1 => ProposalType::AddMember,
-							_ => ProposalType::RemoveMember,
+						// the voting period of a draft starts once it is sponsored
+						let status = if petition {
+							ProposalStatus::Petition
+						} else if !by_group && Self::required_sponsors(group_id) > 0 {
+							ProposalStatus::Draft
+						} else {
+							ProposalStatus::Pending
 						};
 
-						let status = ProposalStatus::Pending;
-
 						let proposal = Proposal {
 							proposal_id,
+							group: group_id,
 							threshold,
 							status,
 							vote: 0,
-							proposaltype: protype,
+							proposaltype,
 							owner: caller.clone(),
+							title,
+							description,
+							voting_mode,
+							revision: 0,
+							tally,
 						};
 
-						match signal {
-							true => {
-								AddMember::<T>::insert(proposal_id, &change_member);
+						match (proposaltype, change_member) {
+							(ProposalType::AddMember, Some(change_member)) => {
+								AddMember::<T, I>::insert(proposal_id, &change_member);
 							},
-							false => {
-								RemoveMember::<T>::insert(proposal_id, &change_member);
+							(ProposalType::RemoveMember, Some(change_member)) => {
+								Self::count_removal(group_id, &change_member, true);
+								RemoveMember::<T, I>::insert(proposal_id, &change_member);
 							},
+							_ => {},
 						}
 
-						Proposals::<T>::insert(proposal_id, &proposal);
+						Proposals::<T, I>::insert(proposal_id, &proposal);
+						GroupProposals::<T, I>::mutate(group_id, |count| *count += 1);
 
-						Self::do_vote(caller.clone(), proposal_id, signal, threshold_u32)?;
+						// the owner of a secret or weighted proposal votes like the other members
+						if voting_mode == VotingMode::Open &&
+							tally == TallyMode::HeadCount &&
+							status == ProposalStatus::Pending &&
+							!by_group
+						{
+							let dynthreshold = Self::approval_threshold(proposal_id);
+
+							Self::do_vote(caller.clone(), proposal_id, true, dynthreshold)?;
+						}
 
 						Self::deposit_event(Event::CreateProposal {
-							who: caller,
+							group_id,
 							proposal_id,
+							proposal_hash: Self::proposal_hash(proposal_id),
+							proposaltype,
+							who: caller,
 							threshold,
 							status,
-						})
+						});
+
+						Ok(proposal_id)
 					}
 				},
-				false => return Err(Error::<T>::NotFoundAccount.into()),
+				false => Err(Error::<T, I>::NotFoundAccount.into()),
+			}
+		}
+
+		/// The threshold a proposal of `proposaltype` is created with in the group, set by the
+		/// group or the default of the type: all of the members to add or remove a member,
+		/// `FreezeThreshold` to freeze the group and more than half of them otherwise.
+		///
+		/// A proposal needs the approvals of its threshold and at least the dynamic threshold of
+		/// the group, but for a `FreezeGroup` proposal which only needs its threshold.
+		pub fn threshold_of(group_id: GroupId, proposaltype: ProposalType) -> ProposalThreshold {
+			if Self::is_unanimous(proposaltype) {
+				return ProposalThreshold::All
 			}
 
+			Thresholds::<T, I>::get(group_id, proposaltype).unwrap_or_else(|| match proposaltype {
+				ProposalType::FreezeGroup => T::FreezeThreshold::get(),
+				ProposalType::AddMember | ProposalType::RemoveMember => ProposalThreshold::All,
+				_ => ProposalThreshold::MoreThanhalf,
+			})
+		}
+
+		// the proposals all of the members must approve, whatever the settings of the group
+		fn is_unanimous(proposaltype: ProposalType) -> bool {
+			matches!(proposaltype, ProposalType::DissolveGroup | ProposalType::SetCallFilter)
+		}
+
+		// the quorum deciding the proposal once its voting period is over, the unanimous
+		// proposals and the ones acting on the group right away are decided by their votes
+		fn quorum_of(proposal: &Proposal<T, I>) -> Option<QuorumConfig> {
+			match proposal.proposaltype {
+				ProposalType::FreezeGroup |
+				ProposalType::UnfreezeGroup |
+				ProposalType::SwapMember => None,
+				proposaltype if Self::is_unanimous(proposaltype) => None,
+				_ => Self::quorum(proposal.group),
+			}
+		}
+
+		pub fn do_change_members(
+			group_id: GroupId,
+			who: T::AccountId,
+			members: &mut Vec<T::AccountId>,
+			signal: bool,
+		) -> DispatchResult {
+			Self::change_multisig_members(group_id, members, signal)?;
+
+			// a member leaving the group ends the delegations from and to it
+			if !signal {
+				for member in members.iter() {
+					Self::remove_delegations(group_id, member);
+				}
+			}
+
+			members.sort();
+			let (incoming, outgoing) = if signal {
+				(members.to_vec(), Vec::new())
+			} else {
+				(Vec::new(), members.to_vec())
+			};
+			Self::track_members(group_id, &incoming, &outgoing);
+			Self::notify_members_changed(group_id, &incoming, &outgoing);
+
+			let dyn_threshold = Self::dyn_threshold_of(Self::members(group_id).len() as u32);
+
+			Self::deposit_event(Event::ChangeGroup {
+				group_id,
+				account: who,
+				incoming,
+				outgoing,
+				dynthreshold: dyn_threshold,
+			});
+
 			Ok(())
 		}
 
-		pub fn do_change_members(who: T::AccountId, members: &mut Vec<T::AccountId>, signal: bool) {
-			let _ = Self::change_multisig_members(members, signal);
+		// replace `old` by `new` in place, keeping the members sorted
+		fn do_swap_member(
+			group_id: GroupId,
+			old: &T::AccountId,
+			new: &T::AccountId,
+		) -> DispatchResult {
+			MultisigMembers::<T, I>::try_mutate(group_id, |members| -> DispatchResult {
+				let index = members.binary_search(old).map_err(|_| Error::<T, I>::InvalidSwap)?;
+				ensure!(members.binary_search(new).is_err(), Error::<T, I>::InvalidSwap);
+
+				members[index] = new.clone();
+				members.sort();
+				Ok(())
+			})?;
+
+			let swap = |who: &mut T::AccountId| {
+				if who == old {
+					*who = new.clone();
+				}
+			};
+
+			// the votes and proposals of the pending proposals of the group
+			let pending = Proposals::<T, I>::iter()
+				.filter(|(_, proposal)| proposal.group == group_id)
+				.map(|(proposal_id, _)| proposal_id)
+				.collect::<Vec<_>>();
+			let mut weighted = false;
+			for proposal_id in pending {
+				// a weighted vote is backed by the balance of the replaced member, it is dropped
+				// and the new member votes with its own balance
+				let released = WeightedVotes::<T, I>::take(proposal_id, old);
+				weighted |= released.is_some();
+
+				Voting::<T, I>::mutate(proposal_id, |vote| {
+					if let Some(vote) = vote {
+						if released.is_some() {
+							vote.ayes.retain(|who| who != old);
+							vote.nays.retain(|who| who != old);
+						}
+						vote.ayes.iter_mut().chain(vote.nays.iter_mut()).for_each(swap);
+						for (delegator, delegate) in vote.delegations.iter_mut() {
+							swap(delegator);
+							swap(delegate);
+						}
+					}
+				});
+
+				if let Some(commitment) = Commitments::<T, I>::take(proposal_id, old) {
+					Commitments::<T, I>::insert(proposal_id, new, commitment);
+				}
+				if Sponsors::<T, I>::contains_key(proposal_id) {
+					Sponsors::<T, I>::mutate(proposal_id, |sponsors| {
+						sponsors.iter_mut().for_each(swap)
+					});
+				}
+
+				Proposals::<T, I>::mutate(proposal_id, |proposal| {
+					if let Some(proposal) = proposal {
+						swap(&mut proposal.owner);
+						if matches!(released, Some((true, _))) {
+							proposal.vote = proposal.vote.saturating_sub(1);
+						}
+					}
+				});
+				if Self::remove_members(proposal_id).as_ref() == Some(old) {
+					Self::count_removal(group_id, old, false);
+					Self::count_removal(group_id, new, true);
+					RemoveMember::<T, I>::insert(proposal_id, new);
+				}
+				if let Some((mut account, replacement)) = Self::swap_members(proposal_id) {
+					swap(&mut account);
+					SwapMember::<T, I>::insert(proposal_id, (account, replacement));
+				}
+			}
+
+			if weighted {
+				Self::update_voting_freeze(old);
+			}
+
+			Self::track_members(group_id, &[new.clone()], &[old.clone()]);
+
+			let count = MemberProposals::<T, I>::take(group_id, old);
+			if count > 0 {
+				MemberProposals::<T, I>::insert(group_id, new, count);
+			}
 
-			let dyn_threshold = Self::calculate_dyn_threshold(members);
+			// the delegations from and to the replaced member
+			if let Some(delegation) = Delegations::<T, I>::take(group_id, old) {
+				Delegations::<T, I>::insert(group_id, new, delegation);
+			}
+			let delegators = Delegations::<T, I>::iter_prefix(group_id)
+				.filter(|(_, (delegate, _))| delegate == old)
+				.map(|(delegator, (_, until))| (delegator, until))
+				.collect::<Vec<_>>();
+			for (delegator, until) in delegators {
+				Delegations::<T, I>::insert(group_id, delegator, (new, until));
+			}
 
-			Self::deposit_event(Event::ChangeGroup { account: who, dynthreshold: dyn_threshold });
+			Self::notify_members_changed(group_id, &[new.clone()], &[old.clone()]);
+
+			Ok(())
 		}
 
 		fn change_multisig_members(
+			group_id: GroupId,
 			members: &mut Vec<T::AccountId>,
 			singal: bool,
 		) -> DispatchResult {
 			match singal {
 				true => {
-					MultisigMembers::<T>::try_mutate(|accounts| -> DispatchResult {
-						accounts.try_append(members).map_err(|_| Error::<T>::MaxMultisigNumber)?;
+					MultisigMembers::<T, I>::try_mutate(group_id, |accounts| -> DispatchResult {
+						for member in members.iter() {
+							ensure!(!accounts.contains(member), Error::<T, I>::AlreadyMember);
+							accounts
+								.try_push(member.clone())
+								.map_err(|_| Error::<T, I>::MaxMultisigNumber)?;
+						}
 						accounts.sort();
 						Ok(())
 					})?;
 				},
 				false => {
-					MultisigMembers::<T>::try_mutate(|accounts| -> DispatchResult {
+					MultisigMembers::<T, I>::try_mutate(group_id, |accounts| -> DispatchResult {
+						ensure!(
+							accounts.len() as u32 > T::MinMultisigNumber::get(),
+							Error::<T, I>::MinMultisigNumber
+						);
 						if let Some(index) = accounts.iter().position(|x| x == &members[0]) {
 							accounts.remove(index);
 							Ok(())
 						} else {
-							Err(Error::<T>::NotFoundAccount.into())
+							Err(Error::<T, I>::NotFoundAccount.into())
 						}
 					})?;
 				},
@@ -637,28 +3859,171 @@ pub mod pallet {
 			Ok(())
 		}
 
+		// share the funds of the group account and clear the group, the dissolving proposal is
+		// recorded once it is finished
+		fn do_dissolve(
+			group_id: GroupId,
+			proposal_id: u32,
+		) -> Result<BalanceOf<T, I>, DispatchError> {
+			let beneficiaries = DissolveBeneficiaries::<T, I>::take(proposal_id)
+				.ok_or(Error::<T, I>::NoBeneficiary)?;
+
+			let account = Self::account_id(group_id);
+			let funds = T::Currency::free_balance(&account);
+			let share = funds / (beneficiaries.len() as u32).into();
+			for beneficiary in beneficiaries.iter().skip(1) {
+				T::Currency::transfer(
+					&account,
+					beneficiary,
+					share,
+					ExistenceRequirement::AllowDeath,
+				)?;
+			}
+			T::Currency::transfer(
+				&account,
+				&beneficiaries[0],
+				T::Currency::free_balance(&account),
+				ExistenceRequirement::AllowDeath,
+			)?;
+
+			// return the deposits and release the calls of the pending proposals
+			let pending = Proposals::<T, I>::iter()
+				.filter(|(_, proposal)| proposal.group == group_id)
+				.map(|(pending, _)| pending)
+				.collect::<Vec<_>>();
+			for pending in pending {
+				Self::clear_call(pending);
+				Proposals::<T, I>::remove(pending);
+				Voting::<T, I>::remove(pending);
+				let _ = Commitments::<T, I>::clear_prefix(pending, u32::MAX, None);
+				AddMember::<T, I>::remove(pending);
+				RemoveMember::<T, I>::remove(pending);
+				SwapMember::<T, I>::remove(pending);
+				DissolveBeneficiaries::<T, I>::remove(pending);
+				ProposedCallFilters::<T, I>::remove(pending);
+				Prerequisites::<T, I>::remove(pending);
+				Dependents::<T, I>::remove(pending);
+				Self::clear_revisions(pending);
+				Sponsors::<T, I>::remove(pending);
+				JointGroups::<T, I>::remove(pending);
+				Self::release_weighted_votes(pending);
+			}
+
+			// the history of the group
+			let mut queue = FinishedQueue::<T, I>::get();
+			queue.retain(|finished| {
+				let of_group = Self::finished_proposal(finished)
+					.is_some_and(|record| record.proposal.group == group_id);
+				if of_group {
+					Self::remove_record(*finished);
+				}
+				!of_group
+			});
+			FinishedQueue::<T, I>::put(queue);
+
+			let members = MultisigMembers::<T, I>::take(group_id);
+			Self::track_members(group_id, &[], &members);
+			Self::notify_members_changed(group_id, &[], &members);
+
+			let _ = Delegations::<T, I>::clear_prefix(group_id, u32::MAX, None);
+			let _ = LastActive::<T, I>::clear_prefix(group_id, u32::MAX, None);
+			let _ = MissedProposals::<T, I>::clear_prefix(group_id, u32::MAX, None);
+			let _ = MemberProposals::<T, I>::clear_prefix(group_id, u32::MAX, None);
+			GroupProposals::<T, I>::remove(group_id);
+			let _ = PendingRemovals::<T, I>::clear_prefix(group_id, u32::MAX, None);
+			let _ = Thresholds::<T, I>::clear_prefix(group_id, u32::MAX, None);
+			Quorum::<T, I>::remove(group_id);
+			RequiredSponsors::<T, I>::remove(group_id);
+			TallyModes::<T, I>::remove(group_id);
+			CallFilters::<T, I>::remove(group_id);
+			Frozen::<T, I>::remove(group_id);
+			Groups::<T, I>::remove(group_id);
+			// the membership traits start a new group on their next change
+			if Self::primary_group() == Some(group_id) {
+				PrimaryGroup::<T, I>::kill();
+			}
+			if let Some((creator, deposit)) = GroupDeposits::<T, I>::take(group_id) {
+				T::Currency::unreserve(&creator, deposit);
+			}
+
+			Ok(funds)
+		}
+
+		// a removal keeps the group at `MinMultisigNumber` members at least
+		fn can_remove_member(group_id: GroupId) -> bool {
+			MultisigMembers::<T, I>::decode_len(group_id).unwrap_or_default() as u32 >
+				T::MinMultisigNumber::get()
+		}
+
 		// multisig group dyn threshold
 		fn calculate_dyn_threshold(members: &Vec<T::AccountId>) -> u32 {
-			let member_numbers = members.len() as u32;
+			Self::dyn_threshold_of(members.len() as u32)
+		}
+
+		fn dyn_threshold_of(member_numbers: u32) -> u32 {
 			match member_numbers {
-				0..=3 => member_numbers,       // must all
-				5 => 2 * (member_numbers % 3), // must 2/3 +
-				_ => member_numbers / 2 + 1,   // must 1/2 +
+				0..=3 => member_numbers,         // must all
+				5 => 2 * member_numbers / 3 + 1, // must 2/3 +
+				_ => member_numbers / 2 + 1,     // must 1/2 +
 			}
 		}
 
-		pub fn proposal_info() -> Vec<(u32, Proposal<T>)> {
-			Proposals::<T>::iter()
+		/// Execute the call of a pending proposal as the group account without committing
+		/// anything, the balances of the group account and `accounts` are compared.
+		pub fn dry_run_proposal(
+			proposal_id: u32,
+			accounts: Vec<T::AccountId>,
+		) -> Result<DryRunResultOf<T, I>, DispatchError> {
+			let group = Self::dispatcher_of(proposal_id, Self::group_of(proposal_id)?);
+
+			let call = Self::call_proposals(proposal_id).ok_or(Error::<T, I>::NotFoundCall)?;
+			let (call, _) = T::Preimages::peek(&call)?;
+			Self::ensure_call_allowed(proposal_id, &call)?;
+			let info = call.get_dispatch_info();
+
+			let accounts = sp_std::iter::once(group.clone()).chain(accounts).collect::<Vec<_>>();
+
+			with_transaction(|| {
+				let before = accounts.iter().map(T::Currency::total_balance).collect::<Vec<_>>();
+				let emitted = frame_system::Pallet::<T>::read_events_no_consensus().count();
+
+				let result = call.dispatch(frame_system::RawOrigin::Signed(group).into());
+				let actual_weight = extract_actual_weight(&result, &info);
+
+				let events = frame_system::Pallet::<T>::read_events_no_consensus()
+					.skip(emitted)
+					.map(|record| record.event)
+					.collect();
+				let balances = accounts
+					.into_iter()
+					.zip(before)
+					.filter_map(|(account, before)| {
+						let after = T::Currency::total_balance(&account);
+						(after != before).then_some((account, before, after))
+					})
+					.collect();
+
+				TransactionOutcome::Rollback(Ok(DryRunResult {
+					result: result.map(|_| ()).map_err(|e| e.error),
+					actual_weight,
+					events,
+					balances,
+				}))
+			})
+		}
+
+		pub fn proposal_info() -> Vec<(u32, Proposal<T, I>)> {
+			Proposals::<T, I>::iter()
 				.filter(|(_id, proposal)| proposal.status == ProposalStatus::Pending)
 				.collect::<Vec<_>>()
 		}
 
-		pub fn finish_proposal() -> Vec<(u32, Proposal<T>)> {
-			FinishedProposal::<T>::iter().collect::<Vec<_>>()
+		pub fn finish_proposal() -> Vec<(u32, ProposalRecord<T, I>)> {
+			FinishedProposal::<T, I>::iter().collect::<Vec<_>>()
 		}
 
-		pub fn multisig_members() -> Vec<T::AccountId> {
-			MultisigMembers::<T>::get().to_vec()
+		pub fn multisig_members(group_id: GroupId) -> Vec<T::AccountId> {
+			MultisigMembers::<T, I>::get(group_id).to_vec()
 		}
 
 		pub fn infos(id: u32) -> (u32, u32) {
@@ -669,3 +4034,64 @@ pub mod pallet {
 		}
 	}
 }
+
+impl<T: Config<I>, I: 'static> Contains<T::AccountId> for Pallet<T, I> {
+	fn contains(who: &T::AccountId) -> bool {
+		Self::primary_members().binary_search(who).is_ok()
+	}
+}
+
+impl<T: Config<I>, I: 'static> SortedMembers<T::AccountId> for Pallet<T, I> {
+	fn sorted_members() -> Vec<T::AccountId> {
+		Self::primary_members()
+	}
+
+	fn count() -> usize {
+		Self::primary_members().len()
+	}
+}
+
+// let another pallet, such as a membership pallet, manage the members of the primary group, a
+// group of its own apart from the ones created by the users
+impl<T: Config<I>, I: 'static> ChangeMembers<T::AccountId> for Pallet<T, I> {
+	fn change_members_sorted(
+		incoming: &[T::AccountId],
+		outgoing: &[T::AccountId],
+		sorted_new: &[T::AccountId],
+	) {
+		// the members set by the membership source are bounded as the members of a group
+		let members = match BoundedVec::try_from(sorted_new.to_vec()) {
+			Ok(members) if members.len() as u32 >= T::MinMultisigNumber::get() => members,
+			_ => {
+				frame_support::defensive!("the new members of the primary group are out of bounds");
+				return
+			},
+		};
+		let group_id = Self::ensure_primary_group();
+		MultisigMembers::<T, I>::insert(group_id, members);
+
+		for member in outgoing {
+			Self::remove_delegations(group_id, member);
+		}
+		Self::track_members(group_id, incoming, outgoing);
+
+		T::MembershipChanged::change_members_sorted(incoming, outgoing, &Self::members(group_id));
+	}
+}
+
+impl<T: Config<I>, I: 'static> InitializeMembers<T::AccountId> for Pallet<T, I> {
+	fn initialize_members(members: &[T::AccountId]) {
+		if !members.is_empty() {
+			assert!(Self::primary_members().is_empty(), "Members are already initialized!");
+
+			let group_id = Self::ensure_primary_group();
+
+			let mut members = members.to_vec();
+			members.sort();
+			Self::track_members(group_id, &members, &[]);
+			let members: BoundedVec<_, T::MaxMultisigNumber> =
+				members.try_into().expect("Too many members!");
+			MultisigMembers::<T, I>::insert(group_id, members);
+		}
+	}
+}
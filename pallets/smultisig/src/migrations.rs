@@ -0,0 +1,181 @@
+//! Storage migrations of the multisig group pallet.
+
+use super::*;
+use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+use sp_std::marker::PhantomData;
+
+/// Version 1 keys the storage by group, the single group of version 0 becomes the first group,
+/// the primary group managed through the membership traits.
+pub mod v1 {
+	use super::*;
+
+	/// The id of the group of version 0.
+	const PRIMARY_GROUP: GroupId = 0;
+
+	/// The storage of version 0, the members of the only group and its add and remove member
+	/// proposals.
+	pub(crate) mod old {
+		use super::*;
+		use frame_support::storage_alias;
+
+		#[derive(Encode, Decode)]
+		pub struct Proposal<T: frame_system::Config> {
+			pub proposal_id: u32,
+			pub threshold: ProposalThreshold,
+			pub status: ProposalStatus,
+			pub vote: u32,
+			pub proposaltype: ProposalType,
+			pub owner: T::AccountId,
+		}
+
+		#[derive(Encode, Decode)]
+		pub struct Votes<T: frame_system::Config> {
+			pub index: ProposalIndex,
+			pub threshold: Threshold,
+			pub ayes: Vec<T::AccountId>,
+			pub nays: Vec<T::AccountId>,
+		}
+
+		#[storage_alias]
+		pub type MultisigMembers<T: Config<I>, I: 'static> = StorageValue<
+			Pallet<T, I>,
+			BoundedVec<<T as frame_system::Config>::AccountId, <T as Config<I>>::MaxMultisigNumber>,
+			ValueQuery,
+		>;
+	}
+
+	// the proposal of the primary group, with the defaults of the fields added since
+	fn upgrade<T: Config<I>, I: 'static>(proposal: old::Proposal<T>) -> Proposal<T, I> {
+		Proposal {
+			proposal_id: proposal.proposal_id,
+			group: PRIMARY_GROUP,
+			threshold: proposal.threshold,
+			status: proposal.status,
+			vote: proposal.vote,
+			proposaltype: proposal.proposaltype,
+			owner: proposal.owner,
+			title: Default::default(),
+			description: None,
+			voting_mode: VotingMode::Open,
+			revision: 0,
+			tally: TallyMode::HeadCount,
+		}
+	}
+
+	/// Moves the members into `PRIMARY_GROUP`, creates the group and indexes its members and
+	/// pending proposals, and translates the proposals, their votes and the finished proposals.
+	pub struct MigrateToV1<T, I = ()>(PhantomData<(T, I)>);
+
+	impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV1<T, I> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T, I>::on_chain_storage_version() >= 1 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let (mut reads, mut writes) = (1u64, 1u64);
+			let now = frame_system::Pallet::<T>::block_number();
+
+			// the value is taken before the map sharing its prefix is written
+			let members = old::MultisigMembers::<T, I>::take();
+			reads += 1;
+			writes += 1;
+
+			// the group is owned by its own account as the one created by `initialize_members`
+			if !members.is_empty() {
+				for member in members.iter() {
+					MemberGroups::<T, I>::mutate(member, |groups| groups.push(PRIMARY_GROUP));
+				}
+				reads += members.len() as u64;
+				writes += members.len() as u64 + 4;
+
+				MultisigMembers::<T, I>::insert(PRIMARY_GROUP, members);
+				Groups::<T, I>::insert(
+					PRIMARY_GROUP,
+					GroupInfo {
+						name: Default::default(),
+						creator: Pallet::<T, I>::account_id(PRIMARY_GROUP),
+						created_at: now,
+					},
+				);
+				GroupCount::<T, I>::put(PRIMARY_GROUP + 1);
+				PrimaryGroup::<T, I>::put(PRIMARY_GROUP);
+			}
+
+			// the ids were reused by version 0, the last one is the highest stored
+			let mut last_id = 0u32;
+			let (mut pending, mut removals) = (0u32, Vec::new());
+			Proposals::<T, I>::translate::<old::Proposal<T>, _>(|proposal_id, proposal| {
+				reads += 1;
+				writes += 1;
+				last_id = last_id.max(proposal_id);
+				pending += 1;
+				MemberProposals::<T, I>::mutate(PRIMARY_GROUP, &proposal.owner, |count| {
+					*count += 1
+				});
+				if proposal.proposaltype == ProposalType::RemoveMember {
+					removals.push(proposal_id);
+				}
+				Some(upgrade::<T, I>(proposal))
+			});
+			if pending > 0 {
+				GroupProposals::<T, I>::insert(PRIMARY_GROUP, pending);
+				writes += 1;
+			}
+			// the pending removals are indexed by the member removed
+			for member in removals.into_iter().filter_map(RemoveMember::<T, I>::get) {
+				PendingRemovals::<T, I>::mutate(PRIMARY_GROUP, member, |count| *count += 1);
+				reads += 1;
+				writes += 1;
+			}
+
+			// the votes of version 0 had no end, the voting period starts with the upgrade
+			let end = now.saturating_add(T::VotingPeriod::get());
+			Voting::<T, I>::translate::<old::Votes<T>, _>(|_, votes| {
+				reads += 1;
+				writes += 1;
+				Some(Votes {
+					index: votes.index,
+					threshold: votes.threshold,
+					ayes: votes.ayes,
+					nays: votes.nays,
+					end,
+					commit_end: None,
+					delegations: Vec::new(),
+				})
+			});
+
+			// a finished proposal which reached its threshold was executed, otherwise rejected
+			let mut queue = Vec::new();
+			FinishedProposal::<T, I>::translate::<old::Proposal<T>, _>(|proposal_id, proposal| {
+				reads += 1;
+				writes += 1;
+				last_id = last_id.max(proposal_id);
+				let threshold = Voting::<T, I>::get(proposal_id).map(|votes| votes.threshold);
+				let outcome = match threshold {
+					Some(threshold) if proposal.vote >= threshold =>
+						ProposalOutcome::Executed(Ok(())),
+					_ => ProposalOutcome::Rejected,
+				};
+				// the records sharing their id with a pending proposal are replaced once it
+				// finishes, pruning them would remove the storage of the pending one
+				if !Proposals::<T, I>::contains_key(proposal_id) {
+					queue.push(proposal_id);
+				}
+				reads += 2;
+				Some(ProposalRecord {
+					proposal: upgrade::<T, I>(proposal),
+					outcome,
+					finished_at: now,
+				})
+			});
+			queue.sort();
+			FinishedQueue::<T, I>::put(queue);
+			ProposalCount::<T, I>::put(last_id);
+			writes += 2;
+
+			StorageVersion::new(1).put::<Pallet<T, I>>();
+
+			T::DbWeight::get().reads_writes(reads, writes)
+		}
+	}
+}
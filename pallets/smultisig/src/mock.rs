@@ -1,27 +1,107 @@
 use crate as pallet_smultisig;
 
-use frame_system::{self as system};
+use frame_system::{self as system, EnsureRoot};
 
 use frame_support::{
+	instances::Instance1,
 	pallet_prelude::ConstU32,
-	traits::{ConstU16, ConstU64},
+	parameter_types,
+	traits::{ChangeMembers, ConstU16, ConstU64, OnIdle, OnInitialize},
+	weights::Weight,
+	PalletId,
 };
 
 use sp_core::H256;
 use sp_runtime::{
 	traits::{BlakeTwo256, IdentityLookup},
-	BuildStorage,
+	BuildStorage, Percent,
 };
 
 // type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
 type Block = frame_system::mocking::MockBlock<Test>;
 
+parameter_types! {
+	pub const MultisigPalletId: PalletId = PalletId(*b"py/smsig");
+	pub const TechnicalPalletId: PalletId = PalletId(*b"py/techc");
+	pub const MultisigFreezeId: u8 = 0;
+	pub const TechnicalFreezeId: u8 = 1;
+	pub const PetitionForfeit: Percent = Percent::from_percent(50);
+	pub const FreezeThreshold: pallet_smultisig::ProposalThreshold =
+		pallet_smultisig::ProposalThreshold::MoreThanhalf;
+	pub static MembersChanged: Vec<u128> = vec![];
+	pub static MaxMissedProposals: u32 = u32::MAX;
+	pub static InactivityPeriod: u64 = u64::MAX;
+	pub static GroupDeposit: u64 = 0;
+	pub static MaxGroupNumber: u32 = 8;
+}
+
+pub struct TestChangeMembers;
+impl ChangeMembers<u128> for TestChangeMembers {
+	fn change_members_sorted(_incoming: &[u128], _outgoing: &[u128], sorted_new: &[u128]) {
+		MembersChanged::set(sorted_new.to_vec());
+	}
+}
+
 impl pallet_smultisig::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = ();
+	type RuntimeCall = RuntimeCall;
+	type Currency = Balances;
+	type Preimages = Preimage;
+	type MembershipChanged = TestChangeMembers;
+	type PalletId = MultisigPalletId;
+	type MaxTitleLength = ConstU32<64>;
+	type MaxGroupNameLength = ConstU32<16>;
+	type ProposalDepositBase = ConstU64<2>;
+	type ProposalDepositPerByte = ConstU64<1>;
 	type MaxMultisigNumber = ConstU32<5>;
-	type MaxProposalNumber = ConstU32<10>;
+	type MaxProposalNumber = ConstU32<5>;
+	type MaxMemberProposalNumber = ConstU32<3>;
 	type MinMultisigNumber = ConstU32<3>;
+	type VotingPeriod = ConstU64<10>;
+	type RevealPeriod = ConstU64<5>;
+	type MaxMissedProposals = MaxMissedProposals;
+	type InactivityPeriod = InactivityPeriod;
+	type FreezeThreshold = FreezeThreshold;
+	type MaxFinishedProposals = ConstU32<2>;
+	type EthVotePriority = ConstU64<100>;
+	type Freezer = Balances;
+	type FreezeId = MultisigFreezeId;
+	type PetitionBond = ConstU64<10>;
+	type PetitionForfeit = PetitionForfeit;
+	type GroupDeposit = GroupDeposit;
+	type MaxGroupNumber = MaxGroupNumber;
+}
+
+impl pallet_smultisig::Config<Instance1> for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = ();
+	type RuntimeCall = RuntimeCall;
+	type Currency = Balances;
+	type Preimages = Preimage;
+	type MembershipChanged = ();
+	type PalletId = TechnicalPalletId;
+	type MaxTitleLength = ConstU32<64>;
+	type MaxGroupNameLength = ConstU32<16>;
+	type ProposalDepositBase = ConstU64<2>;
+	type ProposalDepositPerByte = ConstU64<1>;
+	type MaxMultisigNumber = ConstU32<4>;
+	type MaxProposalNumber = ConstU32<5>;
+	type MaxMemberProposalNumber = ConstU32<3>;
+	type MinMultisigNumber = ConstU32<2>;
+	type VotingPeriod = ConstU64<10>;
+	type RevealPeriod = ConstU64<5>;
+	type MaxMissedProposals = MaxMissedProposals;
+	type InactivityPeriod = InactivityPeriod;
+	type FreezeThreshold = FreezeThreshold;
+	type MaxFinishedProposals = ConstU32<2>;
+	type EthVotePriority = ConstU64<100>;
+	type Freezer = Balances;
+	type FreezeId = TechnicalFreezeId;
+	type PetitionBond = ConstU64<10>;
+	type PetitionForfeit = PetitionForfeit;
+	type GroupDeposit = GroupDeposit;
+	type MaxGroupNumber = MaxGroupNumber;
 }
 
 // Configure a mock runtime to test the pallet.
@@ -29,7 +109,11 @@ frame_support::construct_runtime!(
 	pub enum Test
 	{
 		System: frame_system,
-		MultisigModule: pallet_smultisig::{Pallet,Call,Storage,Event<T>},
+		Balances: pallet_balances,
+		Preimage: pallet_preimage,
+		MultisigModule: pallet_smultisig::{Pallet,Call,Storage,Event<T>,ValidateUnsigned},
+		TechnicalMultisig:
+			pallet_smultisig::<Instance1>::{Pallet,Call,Storage,Event<T>,ValidateUnsigned},
 	}
 );
 
@@ -44,13 +128,13 @@ impl frame_system::Config for Test {
 	type Block = Block;
 	type Hash = H256;
 	type Hashing = BlakeTwo256;
-	type AccountId = u64;
+	type AccountId = u128;
 	type Lookup = IdentityLookup<Self::AccountId>;
 	type RuntimeEvent = RuntimeEvent;
 	type BlockHashCount = ConstU64<250>;
 	type Version = ();
 	type PalletInfo = PalletInfo;
-	type AccountData = ();
+	type AccountData = pallet_balances::AccountData<u64>;
 	type OnNewAccount = ();
 	type OnKilledAccount = ();
 	type SystemWeightInfo = ();
@@ -59,14 +143,55 @@ impl frame_system::Config for Test {
 	type MaxConsumers = frame_support::traits::ConstU32<16>;
 }
 
+impl pallet_balances::Config for Test {
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type Balance = u64;
+	type RuntimeEvent = RuntimeEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ConstU64<1>;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type FreezeIdentifier = u8;
+	type MaxFreezes = ConstU32<2>;
+	type RuntimeHoldReason = ();
+	type MaxHolds = ();
+}
+
+impl pallet_preimage::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = ();
+	type Currency = Balances;
+	type ManagerOrigin = EnsureRoot<u128>;
+	type BaseDeposit = ConstU64<1>;
+	type ByteDeposit = ConstU64<1>;
+}
+
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
-	let t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap().into();
+	let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	pallet_balances::GenesisConfig::<Test> {
+		balances: vec![(1, 100), (2, 100), (3, 100), (4, 100), (5, 100)],
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
 	let mut ext = sp_io::TestExternalities::new(t);
 	ext.execute_with(|| System::set_block_number(1));
 	ext
 }
 
+pub fn run_to_block(n: u64) {
+	while System::block_number() < n {
+		let next = System::block_number() + 1;
+		System::set_block_number(next);
+		MultisigModule::on_initialize(next);
+		MultisigModule::on_idle(next, Weight::MAX);
+		TechnicalMultisig::on_initialize(next);
+		TechnicalMultisig::on_idle(next, Weight::MAX);
+	}
+}
+
 // Checks events against the latest. A contiguous set of events must be provided. They must
 // include the most recent event, but do not have to include every past event.
 pub fn assert_events(mut expected: Vec<RuntimeEvent>) {
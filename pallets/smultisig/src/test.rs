@@ -1,23 +1,47 @@
 use crate::{
 	mock::{RuntimeEvent, *},
-	Event, ProposalStatus, ProposalThreshold,
+	AllowedCall, Event, GroupInfo, GroupTally, ProposalOutcome, ProposalPayload, ProposalRevision,
+	ProposalStatus, ProposalThreshold, ProposalType, QuorumConfig, TallyMode, VotingMode,
 };
-use frame_support::assert_ok;
+use frame_support::{
+	assert_noop, assert_ok,
+	dispatch::GetDispatchInfo,
+	pallet_prelude::DispatchResult,
+	traits::{
+		fungible::InspectFreeze, ChangeMembers, Contains, Currency, Get, InitializeMembers,
+		OnInitialize, QueryPreimage, ReservableCurrency, SortedMembers,
+	},
+	weights::Weight,
+};
+use proptest::prelude::*;
+use sp_core::{ecdsa, Pair, H160, H256};
+use sp_runtime::{
+	traits::{Hash, ValidateUnsigned},
+	transaction_validity::{InvalidTransaction, TransactionSource, TransactionValidity},
+};
+use std::collections::{BTreeMap, BTreeSet};
 #[test]
 fn it_create_multisig_group() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(MultisigModule::create_multisig_group(RuntimeOrigin::signed(1), vec![1, 2, 3]));
+		assert_ok!(MultisigModule::create_multisig_group(
+			RuntimeOrigin::signed(1),
+			vec![1, 2, 3],
+			b"board".to_vec()
+		));
 
-		assert_ok!(MultisigModule::add_member(RuntimeOrigin::signed(1), 4));
+		assert_ok!(MultisigModule::add_member(RuntimeOrigin::signed(1), 0, 4, VotingMode::Open));
 
 		assert_events(vec![RuntimeEvent::MultisigModule(Event::CreateProposal {
-			who: 1,
+			group_id: 0,
 			proposal_id: 1,
+			proposal_hash: MultisigModule::proposal_hash(1),
+			proposaltype: ProposalType::AddMember,
+			who: 1,
 			threshold: ProposalThreshold::All,
 			status: ProposalStatus::Pending,
 		})]);
 
-		assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(2), 1));
+		assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(2), 1, Weight::MAX));
 
 		let proposal_vote = MultisigModule::votings(1).unwrap();
 		assert!(proposal_vote.ayes.contains(&1));
@@ -28,7 +52,7 @@ fn it_create_multisig_group() {
 		let members = MultisigModule::add_members(1).unwrap();
 		assert_eq!(members, 4);
 
-		assert!(!MultisigModule::members().contains(&4));
+		assert!(!MultisigModule::members(0).contains(&4));
 	});
 }
 
@@ -37,59 +61,2781 @@ fn remove_member_work() {
 	new_test_ext().execute_with(|| {
 		assert_ok!(MultisigModule::create_multisig_group(
 			RuntimeOrigin::signed(1),
-			vec![1, 2, 3, 4]
+			vec![1, 2, 3, 4],
+			b"board".to_vec()
 		));
 		assert_events(vec![RuntimeEvent::MultisigModule(Event::CreateMultisig {
+			group_id: 0,
 			who: 1,
 			dyn_threshold: 3,
 		})]);
 
-		assert_ok!(MultisigModule::remove_member(RuntimeOrigin::signed(1), 4));
+		assert_ok!(MultisigModule::remove_member(RuntimeOrigin::signed(1), 0, 4, VotingMode::Open));
 
 		let members = MultisigModule::remove_members(1).unwrap();
 		assert_eq!(members, 4);
 
 		assert_events(vec![RuntimeEvent::MultisigModule(Event::CreateProposal {
-			who: 1,
+			group_id: 0,
 			proposal_id: 1,
+			proposal_hash: MultisigModule::proposal_hash(1),
+			proposaltype: ProposalType::RemoveMember,
+			who: 1,
 			threshold: ProposalThreshold::All,
 			status: ProposalStatus::Pending,
 		})]);
 
-		assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(2), 1));
+		assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(2), 1, Weight::MAX));
 
-		assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(3), 1));
+		assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(3), 1, Weight::MAX));
+		assert!(MultisigModule::members(0).contains(&4));
 
-		assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(4), 1));
+		assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(4), 1, Weight::MAX));
 
-		assert!(!MultisigModule::members().contains(&4));
+		assert!(!MultisigModule::members(0).contains(&4));
 	});
 }
 
 #[test]
 fn it_add_members_into_group_work() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(MultisigModule::create_multisig_group(RuntimeOrigin::signed(1), vec![1, 2, 3]));
+		assert_ok!(MultisigModule::create_multisig_group(
+			RuntimeOrigin::signed(1),
+			vec![1, 2, 3],
+			b"board".to_vec()
+		));
 
-		assert_ok!(MultisigModule::add_member(RuntimeOrigin::signed(1), 4));
+		assert_ok!(MultisigModule::add_member(RuntimeOrigin::signed(1), 0, 4, VotingMode::Open));
 		assert_events(vec![RuntimeEvent::MultisigModule(Event::CreateProposal {
-			who: 1,
+			group_id: 0,
 			proposal_id: 1,
+			proposal_hash: MultisigModule::proposal_hash(1),
+			proposaltype: ProposalType::AddMember,
+			who: 1,
 			threshold: ProposalThreshold::All,
 			status: ProposalStatus::Pending,
 		})]);
 
-		assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(2), 1));
+		assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(2), 1, Weight::MAX));
 
 		let proposal_vote = MultisigModule::votings(1).unwrap();
 		assert!(proposal_vote.ayes.contains(&1));
 		assert!(proposal_vote.ayes.contains(&2));
 
-		assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(3), 1));
+		assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(3), 1, Weight::MAX));
 
 		let members = MultisigModule::add_members(1).unwrap();
 		assert_eq!(members, 4);
 
-		assert!(MultisigModule::members().contains(&4));
+		assert!(MultisigModule::members(0).contains(&4));
+	});
+}
+
+#[test]
+fn propose_call_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(MultisigModule::create_multisig_group(
+			RuntimeOrigin::signed(1),
+			vec![1, 2, 3],
+			b"board".to_vec()
+		));
+
+		let call = Box::new(RuntimeCall::System(frame_system::Call::remark_with_event {
+			remark: b"multisig".to_vec(),
+		}));
+		let description = BlakeTwo256::hash(b"remark from the multisig group");
+
+		assert_noop!(
+			MultisigModule::propose_call(
+				RuntimeOrigin::signed(1),
+				0,
+				vec![0; 65],
+				None,
+				call.clone(),
+				VotingMode::Open,
+				vec![]
+			),
+			crate::Error::<Test>::TitleTooLong
+		);
+
+		assert_ok!(MultisigModule::propose_call(
+			RuntimeOrigin::signed(1),
+			0,
+			b"remark".to_vec(),
+			Some(description),
+			call,
+			VotingMode::Open,
+			vec![]
+		));
+
+		let proposal = MultisigModule::proposals(1).unwrap();
+		assert_eq!(proposal.title.to_vec(), b"remark".to_vec());
+		assert_eq!(proposal.description, Some(description));
+
+		let bounded = MultisigModule::call_proposals(1).unwrap();
+		assert!(Preimage::len(&bounded.hash()).is_some());
+		assert!(Balances::reserved_balance(1) > 0);
+
+		assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(2), 1, Weight::MAX));
+		assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(3), 1, Weight::MAX));
+
+		System::assert_has_event(RuntimeEvent::System(frame_system::Event::Remarked {
+			sender: MultisigModule::account_id(0),
+			hash: BlakeTwo256::hash(b"multisig"),
+		}));
+		System::assert_has_event(RuntimeEvent::MultisigModule(Event::CallExecuted {
+			group_id: 0,
+			proposal_id: 1,
+			result: Ok(()),
+		}));
+
+		assert!(MultisigModule::proposals(1).is_none());
+		assert!(MultisigModule::call_proposals(1).is_none());
+		assert!(Preimage::len(&bounded.hash()).is_none());
+		assert_eq!(Balances::reserved_balance(1), 0);
+	});
+}
+
+#[test]
+fn call_weight_is_bounded_and_charged() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(MultisigModule::create_multisig_group(
+			RuntimeOrigin::signed(1),
+			vec![1, 2, 3],
+			b"board".to_vec()
+		));
+
+		let call = RuntimeCall::System(frame_system::Call::remark_with_event {
+			remark: b"multisig".to_vec(),
+		});
+		let weight = call.get_dispatch_info().weight;
+		assert!(weight.any_gt(Weight::zero()));
+
+		for voting_mode in [VotingMode::Open, VotingMode::CommitReveal] {
+			assert_ok!(MultisigModule::propose_call(
+				RuntimeOrigin::signed(1),
+				0,
+				b"remark".to_vec(),
+				None,
+				Box::new(call.clone()),
+				voting_mode,
+				vec![]
+			));
+		}
+		assert_eq!(MultisigModule::call_weight(1), Some(weight));
+		assert_eq!(MultisigModule::execution_weight(1), weight);
+
+		// a vote which does not execute the proposal only pays for itself
+		let info = MultisigModule::approve(RuntimeOrigin::signed(2), 1, Weight::zero()).unwrap();
+		assert_eq!(info.actual_weight, Some(Weight::from_parts(3_000, 0)));
+
+		// the executing vote must cover the call and pays for it
+		assert_noop!(
+			MultisigModule::approve(
+				RuntimeOrigin::signed(3),
+				1,
+				weight.saturating_sub(Weight::from_parts(1, 0))
+			),
+			crate::Error::<Test>::WrongProposalWeight
+		);
+		let info = MultisigModule::approve(RuntimeOrigin::signed(3), 1, weight).unwrap();
+		assert_eq!(info.actual_weight, Some(Weight::from_parts(3_000, 0).saturating_add(weight)));
+		assert!(MultisigModule::call_weight(1).is_none());
+
+		// the secret votes are counted once the reveal phase is over
+		for who in 1..=3u128 {
+			assert_ok!(MultisigModule::commit_vote(
+				RuntimeOrigin::signed(who),
+				2,
+				BlakeTwo256::hash_of(&(who, 2u32, true, [0u8; 32]))
+			));
+		}
+		run_to_block(12);
+		for who in 1..=3u128 {
+			assert_ok!(MultisigModule::reveal_vote(RuntimeOrigin::signed(who), 2, true, [0u8; 32]));
+		}
+		run_to_block(16);
+		// the weight of the block includes the executed call
+		let closed = MultisigModule::on_initialize(17);
+		assert!(closed.all_gte(weight));
+		assert_eq!(
+			MultisigModule::finished_proposal(2).unwrap().outcome,
+			ProposalOutcome::Executed(Ok(()))
+		);
+	});
+}
+
+#[test]
+fn proposals_share_a_call_preimage() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(MultisigModule::create_multisig_group(
+			RuntimeOrigin::signed(1),
+			vec![1, 2, 3],
+			b"board".to_vec()
+		));
+
+		let call = Box::new(RuntimeCall::System(frame_system::Call::remark_with_event {
+			remark: b"multisig".to_vec(),
+		}));
+		for who in [1, 2] {
+			assert_ok!(MultisigModule::propose_call(
+				RuntimeOrigin::signed(who),
+				0,
+				b"remark".to_vec(),
+				None,
+				call.clone(),
+				VotingMode::Open,
+				vec![]
+			));
+		}
+		let bounded = MultisigModule::call_proposals(1).unwrap();
+		assert_eq!(MultisigModule::call_proposals(2), Some(bounded.clone()));
+
+		// the preimage is kept for the other proposal
+		assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(2), 1, Weight::MAX));
+		assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(3), 1, Weight::MAX));
+		assert_eq!(
+			MultisigModule::finished_proposal(1).unwrap().outcome,
+			ProposalOutcome::Executed(Ok(()))
+		);
+		assert!(Preimage::len(&bounded.hash()).is_some());
+
+		assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(1), 2, Weight::MAX));
+		assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(3), 2, Weight::MAX));
+		assert_eq!(
+			MultisigModule::finished_proposal(2).unwrap().outcome,
+			ProposalOutcome::Executed(Ok(()))
+		);
+		assert!(Preimage::len(&bounded.hash()).is_none());
+	});
+}
+
+#[test]
+fn proposal_outcome_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(MultisigModule::create_multisig_group(
+			RuntimeOrigin::signed(1),
+			vec![1, 2, 3],
+			b"board".to_vec()
+		));
+
+		// rejected
+		assert_ok!(MultisigModule::add_member(RuntimeOrigin::signed(1), 0, 4, VotingMode::Open));
+		assert_ok!(MultisigModule::reject(RuntimeOrigin::signed(2), 1));
+		assert_eq!(
+			MultisigModule::finished_proposal(1).unwrap().outcome,
+			ProposalOutcome::Rejected
+		);
+
+		// cancelled by the owner only
+		assert_ok!(MultisigModule::add_member(RuntimeOrigin::signed(2), 0, 4, VotingMode::Open));
+		assert_noop!(
+			MultisigModule::cancel_proposal(RuntimeOrigin::signed(1), 2),
+			crate::Error::<Test>::NotProposalOwner
+		);
+		assert_ok!(MultisigModule::cancel_proposal(RuntimeOrigin::signed(2), 2));
+		assert_eq!(
+			MultisigModule::finished_proposal(2).unwrap().outcome,
+			ProposalOutcome::Cancelled
+		);
+
+		// expired once the voting period is over
+		assert_ok!(MultisigModule::add_member(RuntimeOrigin::signed(3), 0, 4, VotingMode::Open));
+		run_to_block(12);
+		let record = MultisigModule::finished_proposal(3).unwrap();
+		assert_eq!(record.outcome, ProposalOutcome::Expired);
+		assert_eq!(record.finished_at, 12);
+
+		// executed
+		assert_ok!(MultisigModule::add_member(RuntimeOrigin::signed(1), 0, 4, VotingMode::Open));
+		assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(2), 4, Weight::MAX));
+		assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(3), 4, Weight::MAX));
+		assert_eq!(
+			MultisigModule::finished_proposal(4).unwrap().outcome,
+			ProposalOutcome::Executed(Ok(()))
+		);
+		assert!(MultisigModule::members(0).contains(&4));
+	});
+}
+
+#[test]
+fn finished_history_is_pruned_on_idle() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(MultisigModule::create_multisig_group(
+			RuntimeOrigin::signed(1),
+			vec![1, 2, 3],
+			b"board".to_vec()
+		));
+
+		assert_ok!(MultisigModule::add_member(RuntimeOrigin::signed(1), 0, 4, VotingMode::Open));
+		assert_ok!(MultisigModule::add_member(RuntimeOrigin::signed(1), 0, 5, VotingMode::Open));
+		assert_ok!(MultisigModule::add_member(RuntimeOrigin::signed(1), 0, 6, VotingMode::Open));
+		for proposal_id in 1..=3 {
+			assert_ok!(MultisigModule::cancel_proposal(RuntimeOrigin::signed(1), proposal_id));
+		}
+		assert_eq!(MultisigModule::finished_queue(), vec![1, 2, 3]);
+
+		run_to_block(2);
+
+		assert_eq!(MultisigModule::finished_queue(), vec![2, 3]);
+		assert!(MultisigModule::finished_proposal(1).is_none());
+		assert!(MultisigModule::votings(1).is_none());
+		assert!(MultisigModule::finished_proposal(3).is_some());
+		System::assert_has_event(RuntimeEvent::MultisigModule(Event::PrunedProposals { count: 1 }));
+	});
+}
+
+#[test]
+fn commit_reveal_voting_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(MultisigModule::create_multisig_group(
+			RuntimeOrigin::signed(1),
+			vec![1, 2, 3],
+			b"board".to_vec()
+		));
+
+		let commitment = |who: u128, proposal_id: u32, approve: bool| {
+			BlakeTwo256::hash_of(&(who, proposal_id, approve, [who as u8; 32]))
+		};
+
+		assert_ok!(MultisigModule::add_member(
+			RuntimeOrigin::signed(1),
+			0,
+			4,
+			VotingMode::CommitReveal
+		));
+		assert_ok!(MultisigModule::add_member(
+			RuntimeOrigin::signed(1),
+			0,
+			5,
+			VotingMode::CommitReveal
+		));
+		assert_noop!(
+			MultisigModule::approve(RuntimeOrigin::signed(2), 1, Weight::MAX),
+			crate::Error::<Test>::CommitRevealVoting
+		);
+
+		for who in 1..=3 {
+			assert_ok!(MultisigModule::commit_vote(
+				RuntimeOrigin::signed(who),
+				1,
+				commitment(who, 1, true)
+			));
+		}
+		assert_ok!(MultisigModule::commit_vote(
+			RuntimeOrigin::signed(1),
+			2,
+			commitment(1, 2, true)
+		));
+		assert_ok!(MultisigModule::commit_vote(
+			RuntimeOrigin::signed(2),
+			2,
+			commitment(2, 2, true)
+		));
+		assert_noop!(
+			MultisigModule::reveal_vote(RuntimeOrigin::signed(1), 1, true, [1; 32]),
+			crate::Error::<Test>::NotRevealPhase
+		);
+		// nothing is visible during the commit phase
+		assert!(MultisigModule::votings(1).unwrap().ayes.is_empty());
+
+		run_to_block(12);
+		assert_noop!(
+			MultisigModule::commit_vote(RuntimeOrigin::signed(3), 2, commitment(3, 2, true)),
+			crate::Error::<Test>::NotCommitPhase
+		);
+		assert_noop!(
+			MultisigModule::reveal_vote(RuntimeOrigin::signed(1), 1, false, [1; 32]),
+			crate::Error::<Test>::InvalidReveal
+		);
+		for who in 1..=3 {
+			assert_ok!(MultisigModule::reveal_vote(
+				RuntimeOrigin::signed(who),
+				1,
+				true,
+				[who as u8; 32]
+			));
+		}
+		assert_ok!(MultisigModule::reveal_vote(RuntimeOrigin::signed(1), 2, true, [1; 32]));
+
+		// counted only once the reveal phase is over
+		assert!(!MultisigModule::members(0).contains(&4));
+		run_to_block(17);
+		assert!(MultisigModule::members(0).contains(&4));
+		assert_eq!(
+			MultisigModule::finished_proposal(1).unwrap().outcome,
+			ProposalOutcome::Executed(Ok(()))
+		);
+
+		// the unrevealed vote of 2 is an abstention
+		assert_eq!(
+			MultisigModule::finished_proposal(2).unwrap().outcome,
+			ProposalOutcome::Rejected
+		);
+		assert!(!MultisigModule::members(0).contains(&5));
+	});
+}
+
+#[test]
+fn delegate_vote_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(MultisigModule::create_multisig_group(
+			RuntimeOrigin::signed(1),
+			vec![1, 2, 3],
+			b"board".to_vec()
+		));
+
+		assert_noop!(
+			MultisigModule::delegate_vote(RuntimeOrigin::signed(1), 0, 4, 5),
+			crate::Error::<Test>::NotFoundAccount
+		);
+		assert_ok!(MultisigModule::delegate_vote(RuntimeOrigin::signed(1), 0, 2, 5));
+
+		assert_ok!(MultisigModule::add_member(RuntimeOrigin::signed(3), 0, 4, VotingMode::Open));
+		assert_noop!(
+			MultisigModule::vote_as_delegate(RuntimeOrigin::signed(3), 1, 1, true, Weight::MAX),
+			crate::Error::<Test>::NotDelegate
+		);
+		assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(2), 1, Weight::MAX));
+		assert_ok!(MultisigModule::vote_as_delegate(
+			RuntimeOrigin::signed(2),
+			1,
+			1,
+			true,
+			Weight::MAX
+		));
+
+		let vote = MultisigModule::votings(1).unwrap();
+		assert!(vote.ayes.contains(&1));
+		assert_eq!(vote.delegations, vec![(1, 2)]);
+		assert!(MultisigModule::members(0).contains(&4));
+
+		// ends with the period
+		assert_ok!(MultisigModule::add_member(RuntimeOrigin::signed(3), 0, 5, VotingMode::Open));
+		run_to_block(6);
+		assert_noop!(
+			MultisigModule::vote_as_delegate(RuntimeOrigin::signed(2), 1, 2, true, Weight::MAX),
+			crate::Error::<Test>::DelegationExpired
+		);
+
+		// ends when the delegate leaves the group
+		assert_ok!(MultisigModule::delegate_vote(RuntimeOrigin::signed(1), 0, 3, 20));
+		assert_ok!(MultisigModule::remove_member(RuntimeOrigin::signed(2), 0, 3, VotingMode::Open));
+		assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(1), 3, Weight::MAX));
+		assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(3), 3, Weight::MAX));
+		assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(4), 3, Weight::MAX));
+
+		assert!(!MultisigModule::members(0).contains(&3));
+		assert!(MultisigModule::delegations(0, 1).is_none());
+		System::assert_has_event(RuntimeEvent::MultisigModule(Event::DelegationRemoved {
+			group_id: 0,
+			who: 1,
+		}));
+	});
+}
+
+#[test]
+fn freeze_group_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(MultisigModule::create_multisig_group(
+			RuntimeOrigin::signed(1),
+			vec![1, 2, 3],
+			b"board".to_vec()
+		));
+		assert_ok!(MultisigModule::add_member(RuntimeOrigin::signed(1), 0, 4, VotingMode::Open));
+
+		assert_noop!(
+			MultisigModule::unfreeze_group(RuntimeOrigin::signed(2), 0),
+			crate::Error::<Test>::NotFrozen
+		);
+
+		// more than half of the members is enough to freeze
+		assert_ok!(MultisigModule::freeze_group(RuntimeOrigin::signed(2), 0));
+		assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(3), 2, Weight::MAX));
+		assert_eq!(MultisigModule::frozen(0), Some(1));
+		System::assert_has_event(RuntimeEvent::MultisigModule(Event::GroupFrozen {
+			group_id: 0,
+			proposal_id: 2,
+		}));
+
+		assert_noop!(
+			MultisigModule::approve(RuntimeOrigin::signed(2), 1, Weight::MAX),
+			crate::Error::<Test>::GroupFrozen
+		);
+		assert_noop!(
+			MultisigModule::add_member(RuntimeOrigin::signed(1), 0, 5, VotingMode::Open),
+			crate::Error::<Test>::GroupFrozen
+		);
+
+		assert_ok!(MultisigModule::unfreeze_group(RuntimeOrigin::signed(1), 0));
+		assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(2), 3, Weight::MAX));
+		assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(3), 3, Weight::MAX));
+		assert_eq!(MultisigModule::frozen(0), None);
+
+		assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(2), 1, Weight::MAX));
+	});
+}
+
+#[test]
+fn membership_traits_work() {
+	new_test_ext().execute_with(|| {
+		<MultisigModule as InitializeMembers<u128>>::initialize_members(&[3, 1, 2]);
+		assert_eq!(MultisigModule::primary_group(), Some(0));
+
+		assert!(<MultisigModule as Contains<u128>>::contains(&2));
+		assert!(!<MultisigModule as Contains<u128>>::contains(&4));
+		assert_eq!(<MultisigModule as SortedMembers<u128>>::sorted_members(), vec![1, 2, 3]);
+
+		// the hook is notified once the proposal changed the members
+		assert_ok!(MultisigModule::add_member(RuntimeOrigin::signed(1), 0, 4, VotingMode::Open));
+		assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(2), 1, Weight::MAX));
+		assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(3), 1, Weight::MAX));
+		assert_eq!(MembersChanged::get(), vec![1, 2, 3, 4]);
+
+		<MultisigModule as ChangeMembers<u128>>::change_members_sorted(&[5], &[1], &[2, 3, 4, 5]);
+		assert_eq!(MultisigModule::members(0).to_vec(), vec![2, 3, 4, 5]);
+		assert_eq!(MembersChanged::get(), vec![2, 3, 4, 5]);
+	});
+}
+
+#[test]
+fn change_members_creates_the_primary_group() {
+	new_test_ext().execute_with(|| {
+		GroupDeposit::set(10);
+		assert_ok!(MultisigModule::create_multisig_group(
+			RuntimeOrigin::signed(4),
+			vec![3, 4, 5],
+			b"board".to_vec()
+		));
+
+		// the group of the users is left alone
+		<MultisigModule as ChangeMembers<u128>>::change_members_sorted(&[1, 2, 3], &[], &[1, 2, 3]);
+
+		assert_eq!(MultisigModule::primary_group(), Some(1));
+		assert_eq!(MultisigModule::members(1).to_vec(), vec![1, 2, 3]);
+		assert_eq!(
+			MultisigModule::groups(1).map(|info| info.creator),
+			Some(MultisigModule::account_id(1))
+		);
+		assert_eq!(MultisigModule::group_count(), 2);
+		assert_eq!(MultisigModule::groups_of(2), vec![1]);
+		assert_eq!(MultisigModule::groups_of(3), vec![0, 1]);
+		assert!(MultisigModule::group_deposit(1).is_none());
+
+		assert_eq!(MultisigModule::members(0).to_vec(), vec![3, 4, 5]);
+		assert_eq!(MultisigModule::groups(0).map(|info| info.creator), Some(4));
+		assert_eq!(MultisigModule::group_deposit(0), Some((4, 10)));
+		assert_eq!(<MultisigModule as SortedMembers<u128>>::sorted_members(), vec![1, 2, 3]);
+	});
+}
+
+#[test]
+#[should_panic(expected = "Defensive failure")]
+fn change_members_keeps_the_bounds() {
+	new_test_ext().execute_with(|| {
+		<MultisigModule as ChangeMembers<u128>>::change_members_sorted(&[1, 2, 3], &[], &[1, 2, 3]);
+
+		// below `MinMultisigNumber`
+		<MultisigModule as ChangeMembers<u128>>::change_members_sorted(&[], &[3], &[1, 2]);
+	});
+}
+
+#[test]
+fn instances_are_isolated() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(MultisigModule::create_multisig_group(
+			RuntimeOrigin::signed(1),
+			vec![1, 2, 3],
+			b"board".to_vec()
+		));
+		assert_ok!(TechnicalMultisig::create_multisig_group(
+			RuntimeOrigin::signed(4),
+			vec![4, 5],
+			b"board".to_vec()
+		));
+		assert_events(vec![
+			RuntimeEvent::MultisigModule(Event::CreateMultisig {
+				group_id: 0,
+				who: 1,
+				dyn_threshold: 3,
+			}),
+			RuntimeEvent::TechnicalMultisig(Event::CreateMultisig {
+				group_id: 0,
+				who: 4,
+				dyn_threshold: 2,
+			}),
+		]);
+
+		assert_eq!(MultisigModule::members(0).to_vec(), vec![1, 2, 3]);
+		assert_eq!(TechnicalMultisig::members(0).to_vec(), vec![4, 5]);
+		assert_ne!(MultisigModule::account_id(0), TechnicalMultisig::account_id(0));
+
+		// a proposal in one instance is unknown to the other
+		assert_ok!(MultisigModule::add_member(RuntimeOrigin::signed(1), 0, 4, VotingMode::Open));
+		assert!(MultisigModule::proposals(1).is_some());
+		assert!(TechnicalMultisig::proposals(1).is_none());
+		assert_noop!(
+			TechnicalMultisig::approve(RuntimeOrigin::signed(5), 1, Weight::MAX),
+			crate::Error::<Test, frame_support::instances::Instance1>::NotFoundProposal
+		);
+
+		assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(2), 1, Weight::MAX));
+		assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(3), 1, Weight::MAX));
+		assert_eq!(MultisigModule::members(0).to_vec(), vec![1, 2, 3, 4]);
+		assert_eq!(TechnicalMultisig::members(0).to_vec(), vec![4, 5]);
+		assert_eq!(TechnicalMultisig::proposal_count(), 0);
+	});
+}
+
+#[test]
+fn proposal_dependencies_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(MultisigModule::create_multisig_group(
+			RuntimeOrigin::signed(1),
+			vec![1, 2, 3],
+			b"board".to_vec()
+		));
+
+		let remark = |remark: &[u8]| {
+			Box::new(RuntimeCall::System(frame_system::Call::remark_with_event {
+				remark: remark.to_vec(),
+			}))
+		};
+		let propose = |remark_of: &[u8], prerequisites: Vec<u32>| {
+			MultisigModule::propose_call(
+				RuntimeOrigin::signed(1),
+				0,
+				b"release".to_vec(),
+				None,
+				remark(remark_of),
+				VotingMode::Open,
+				prerequisites,
+			)
+		};
+
+		assert_noop!(propose(b"b", vec![9]), crate::Error::<Test>::InvalidPrerequisite);
+
+		assert_ok!(propose(b"a", vec![]));
+		assert_ok!(propose(b"b", vec![1]));
+		assert_eq!(MultisigModule::dependents(1), vec![2]);
+
+		// the dependent proposal reaches the threshold first and waits
+		assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(2), 2, Weight::MAX));
+		assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(3), 2, Weight::MAX));
+		assert_events(vec![RuntimeEvent::MultisigModule(Event::AwaitingDependencies {
+			group_id: 0,
+			proposal_id: 2,
+			prerequisites: vec![1],
+		})]);
+		assert_eq!(
+			MultisigModule::proposals(2).unwrap().status,
+			ProposalStatus::AwaitingDependencies
+		);
+
+		// the prerequisite expires, so the waiting proposal fails
+		run_to_block(20);
+		assert!(MultisigModule::proposals(2).is_none());
+		assert_eq!(
+			MultisigModule::finished_proposal(2).unwrap().outcome,
+			ProposalOutcome::DependencyFailed(1)
+		);
+	});
+}
+
+#[test]
+fn waiting_proposals_are_not_voted_on() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(MultisigModule::create_multisig_group(
+			RuntimeOrigin::signed(1),
+			vec![1, 2, 3, 4, 5],
+			b"board".to_vec()
+		));
+
+		for prerequisites in [vec![], vec![1]] {
+			assert_ok!(MultisigModule::propose_call(
+				RuntimeOrigin::signed(1),
+				0,
+				b"release".to_vec(),
+				None,
+				Box::new(RuntimeCall::System(frame_system::Call::remark { remark: vec![] })),
+				VotingMode::Open,
+				prerequisites,
+			));
+		}
+		for who in [2, 3, 4] {
+			assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(who), 2, Weight::MAX));
+		}
+		assert_eq!(
+			MultisigModule::proposals(2).unwrap().status,
+			ProposalStatus::AwaitingDependencies
+		);
+
+		// the last member can not vote on the approved proposal anymore
+		assert_noop!(
+			MultisigModule::approve(RuntimeOrigin::signed(5), 2, Weight::MAX),
+			crate::Error::<Test>::InvalidVote
+		);
+		assert_noop!(
+			MultisigModule::reject(RuntimeOrigin::signed(5), 2),
+			crate::Error::<Test>::InvalidVote
+		);
+	});
+}
+
+#[test]
+fn proposal_dependencies_execute_in_order() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(MultisigModule::create_multisig_group(
+			RuntimeOrigin::signed(1),
+			vec![1, 2, 3],
+			b"board".to_vec()
+		));
+
+		let propose = |remark: &[u8], prerequisites: Vec<u32>| {
+			MultisigModule::propose_call(
+				RuntimeOrigin::signed(1),
+				0,
+				b"release".to_vec(),
+				None,
+				Box::new(RuntimeCall::System(frame_system::Call::remark_with_event {
+					remark: remark.to_vec(),
+				})),
+				VotingMode::Open,
+				prerequisites,
+			)
+		};
+
+		assert_ok!(propose(b"a", vec![]));
+		assert_ok!(propose(b"b", vec![1]));
+		assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(2), 2, Weight::MAX));
+		assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(3), 2, Weight::MAX));
+
+		// executing the prerequisite executes the waiting proposal
+		assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(2), 1, Weight::MAX));
+		assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(3), 1, Weight::MAX));
+		assert_events(vec![
+			RuntimeEvent::MultisigModule(Event::CallExecuted {
+				group_id: 0,
+				proposal_id: 1,
+				result: Ok(()),
+			}),
+			RuntimeEvent::MultisigModule(Event::FinishedProposal {
+				group_id: 0,
+				proposal_id: 1,
+				vote: 3,
+				outcome: ProposalOutcome::Executed(Ok(())),
+			}),
+			RuntimeEvent::System(frame_system::Event::Remarked {
+				sender: MultisigModule::account_id(0),
+				hash: BlakeTwo256::hash(b"b"),
+			}),
+			RuntimeEvent::MultisigModule(Event::CallExecuted {
+				group_id: 0,
+				proposal_id: 2,
+				result: Ok(()),
+			}),
+			RuntimeEvent::MultisigModule(Event::FinishedProposal {
+				group_id: 0,
+				proposal_id: 2,
+				vote: 3,
+				outcome: ProposalOutcome::Executed(Ok(())),
+			}),
+		]);
+
+		// an executed prerequisite is already satisfied, a cancelled one fails the dependent
+		assert_ok!(propose(b"c", vec![1]));
+		assert!(MultisigModule::prerequisites(3).is_empty());
+		assert_ok!(propose(b"d", vec![3]));
+		assert_ok!(MultisigModule::cancel_proposal(RuntimeOrigin::signed(1), 3));
+		assert_eq!(
+			MultisigModule::finished_proposal(4).unwrap().outcome,
+			ProposalOutcome::DependencyFailed(3)
+		);
+		assert_noop!(propose(b"e", vec![3]), crate::Error::<Test>::InvalidPrerequisite);
+	});
+}
+
+#[test]
+fn swap_member_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(MultisigModule::create_multisig_group(
+			RuntimeOrigin::signed(1),
+			vec![1, 2, 3],
+			b"board".to_vec()
+		));
+
+		assert_noop!(
+			MultisigModule::swap_member(RuntimeOrigin::signed(1), 0, 4, 5, VotingMode::Open),
+			crate::Error::<Test>::InvalidSwap
+		);
+		assert_noop!(
+			MultisigModule::swap_member(RuntimeOrigin::signed(1), 0, 2, 3, VotingMode::Open),
+			crate::Error::<Test>::InvalidSwap
+		);
+
+		// a pending vote and a delegation of the replaced member
+		assert_ok!(MultisigModule::add_member(RuntimeOrigin::signed(1), 0, 5, VotingMode::Open));
+		assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(2), 1, Weight::MAX));
+		assert_ok!(MultisigModule::delegate_vote(RuntimeOrigin::signed(3), 0, 2, 50));
+
+		assert_ok!(MultisigModule::swap_member(
+			RuntimeOrigin::signed(1),
+			0,
+			2,
+			4,
+			VotingMode::Open
+		));
+		assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(3), 2, Weight::MAX));
+		assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(2), 2, Weight::MAX));
+
+		System::assert_has_event(RuntimeEvent::MultisigModule(Event::MemberSwapped {
+			group_id: 0,
+			proposal_id: 2,
+			old: 2,
+			new: 4,
+		}));
+		assert_eq!(MultisigModule::members(0).to_vec(), vec![1, 3, 4]);
+		assert_eq!(MultisigModule::votings(1).unwrap().ayes, vec![1, 4]);
+		assert_eq!(MultisigModule::delegations(0, 3), Some((4, 50)));
+
+		// the new member finishes the pending proposal
+		assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(3), 1, Weight::MAX));
+		assert_eq!(MultisigModule::members(0).to_vec(), vec![1, 3, 4, 5]);
+	});
+}
+
+#[test]
+fn quorum_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(MultisigModule::create_multisig_group(
+			RuntimeOrigin::signed(1),
+			vec![1, 2, 3, 4],
+			b"board".to_vec()
+		));
+
+		let quorum = QuorumConfig {
+			turnout: sp_runtime::Percent::from_percent(60),
+			approval: sp_runtime::Percent::from_percent(60),
+		};
+		assert_noop!(
+			MultisigModule::set_quorum(RuntimeOrigin::signed(1), 0, Some(quorum)),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(MultisigModule::set_quorum(
+			RuntimeOrigin::signed(MultisigModule::account_id(0)),
+			0,
+			Some(quorum)
+		));
+
+		// approved by the votes cast, but only 2 of the 3 needed members voted
+		assert_ok!(MultisigModule::add_member(RuntimeOrigin::signed(1), 0, 5, VotingMode::Open));
+		assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(2), 1, Weight::MAX));
+
+		// 3 revealed votes reach the quorum and 2 of them approve, short of the 4 needed to remove
+		assert_ok!(MultisigModule::remove_member(
+			RuntimeOrigin::signed(1),
+			0,
+			4,
+			VotingMode::CommitReveal
+		));
+		for (who, approve) in [(1, true), (2, true), (3, false)] {
+			assert_ok!(MultisigModule::commit_vote(
+				RuntimeOrigin::signed(who),
+				2,
+				BlakeTwo256::hash_of(&(who, 2u32, approve, [0u8; 32]))
+			));
+		}
+		run_to_block(12);
+		for (who, approve) in [(1, true), (2, true), (3, false)] {
+			assert_ok!(MultisigModule::reveal_vote(
+				RuntimeOrigin::signed(who),
+				2,
+				approve,
+				[0; 32]
+			));
+		}
+
+		run_to_block(17);
+		assert_eq!(
+			MultisigModule::finished_proposal(1).unwrap().outcome,
+			ProposalOutcome::FailedQuorum
+		);
+		assert_eq!(
+			MultisigModule::finished_proposal(2).unwrap().outcome,
+			ProposalOutcome::Rejected
+		);
+		assert_eq!(MultisigModule::members(0).to_vec(), vec![1, 2, 3, 4]);
+	});
+}
+
+#[test]
+fn quorum_decides_open_votes() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(MultisigModule::create_multisig_group(
+			RuntimeOrigin::signed(1),
+			vec![1, 2, 3, 4],
+			b"board".to_vec()
+		));
+		assert_ok!(MultisigModule::set_quorum(
+			RuntimeOrigin::signed(MultisigModule::account_id(0)),
+			0,
+			Some(QuorumConfig {
+				turnout: sp_runtime::Percent::from_percent(60),
+				approval: sp_runtime::Percent::from_percent(60),
+			})
+		));
+
+		// neither executed nor rejected before the voting period is over
+		assert_ok!(MultisigModule::add_member(RuntimeOrigin::signed(1), 0, 5, VotingMode::Open));
+		for member in 2..=4 {
+			assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(member), 1, Weight::MAX));
+		}
+		assert_ok!(MultisigModule::remove_member(RuntimeOrigin::signed(1), 0, 4, VotingMode::Open));
+		for member in 2..=4 {
+			assert_ok!(MultisigModule::reject(RuntimeOrigin::signed(member), 2));
+		}
+		assert_eq!(MultisigModule::proposals(1).unwrap().status, ProposalStatus::Pending);
+		assert_eq!(MultisigModule::proposals(2).unwrap().status, ProposalStatus::Pending);
+		assert_eq!(MultisigModule::members(0).to_vec(), vec![1, 2, 3, 4]);
+
+		run_to_block(12);
+		assert_eq!(
+			MultisigModule::finished_proposal(1).unwrap().outcome,
+			ProposalOutcome::Executed(Ok(()))
+		);
+		assert_eq!(
+			MultisigModule::finished_proposal(2).unwrap().outcome,
+			ProposalOutcome::Rejected
+		);
+		assert_eq!(MultisigModule::members(0).to_vec(), vec![1, 2, 3, 4, 5]);
+	});
+}
+
+#[test]
+fn quorum_keeps_thresholds() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(MultisigModule::create_multisig_group(
+			RuntimeOrigin::signed(1),
+			vec![1, 2, 3, 4],
+			b"board".to_vec()
+		));
+		assert_ok!(MultisigModule::set_quorum(
+			RuntimeOrigin::signed(MultisigModule::account_id(0)),
+			0,
+			Some(QuorumConfig {
+				turnout: sp_runtime::Percent::from_percent(60),
+				approval: sp_runtime::Percent::from_percent(60),
+			})
+		));
+
+		// the group is frozen and unfrozen as soon as the threshold approves
+		assert_ok!(MultisigModule::freeze_group(RuntimeOrigin::signed(1), 0));
+		assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(2), 1, Weight::MAX));
+		assert_eq!(MultisigModule::frozen(0), None);
+		assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(3), 1, Weight::MAX));
+		assert_eq!(MultisigModule::frozen(0), Some(1));
+
+		assert_ok!(MultisigModule::unfreeze_group(RuntimeOrigin::signed(1), 0));
+		assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(2), 2, Weight::MAX));
+		assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(3), 2, Weight::MAX));
+		assert_eq!(MultisigModule::frozen(0), None);
+
+		// all of the 3 votes cast approve, but removing a member needs all of them
+		assert_ok!(MultisigModule::remove_member(RuntimeOrigin::signed(1), 0, 4, VotingMode::Open));
+		for member in 2..=3 {
+			assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(member), 3, Weight::MAX));
+		}
+
+		run_to_block(12);
+		assert_eq!(
+			MultisigModule::finished_proposal(3).unwrap().outcome,
+			ProposalOutcome::Rejected
+		);
+		assert_eq!(MultisigModule::members(0).to_vec(), vec![1, 2, 3, 4]);
 	});
 }
+
+#[test]
+fn dry_run_proposal_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(MultisigModule::create_multisig_group(
+			RuntimeOrigin::signed(1),
+			vec![1, 2, 3],
+			b"board".to_vec()
+		));
+		let group = MultisigModule::account_id(0);
+		Balances::make_free_balance_be(&group, 50);
+
+		let call = Box::new(RuntimeCall::Balances(pallet_balances::Call::transfer_keep_alive {
+			dest: 6,
+			value: 20,
+		}));
+		assert_ok!(MultisigModule::propose_call(
+			RuntimeOrigin::signed(1),
+			0,
+			b"pay".to_vec(),
+			None,
+			call,
+			VotingMode::Open,
+			vec![]
+		));
+
+		let dry_run = MultisigModule::dry_run_proposal(1, vec![6]).unwrap();
+		assert_eq!(dry_run.result, Ok(()));
+		assert!(dry_run.events.contains(&RuntimeEvent::Balances(
+			pallet_balances::Event::Transfer { from: group, to: 6, amount: 20 }
+		)));
+		assert_eq!(dry_run.balances, vec![(group, 50, 30), (6, 0, 20)]);
+
+		// nothing was committed
+		assert_eq!(Balances::free_balance(group), 50);
+		assert_eq!(Balances::free_balance(6), 0);
+		assert!(MultisigModule::proposals(1).is_some());
+
+		assert_noop!(
+			MultisigModule::dry_run_proposal(2, vec![]),
+			crate::Error::<Test>::NotFoundProposal
+		);
+	});
+}
+
+#[test]
+fn pending_proposals_are_capped() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(MultisigModule::create_multisig_group(
+			RuntimeOrigin::signed(1),
+			vec![1, 2, 3],
+			b"board".to_vec()
+		));
+
+		for member in 4..=6 {
+			assert_ok!(MultisigModule::add_member(
+				RuntimeOrigin::signed(1),
+				0,
+				member,
+				VotingMode::Open
+			));
+		}
+		assert_eq!(MultisigModule::member_proposals(0, 1), 3);
+		assert_noop!(
+			MultisigModule::add_member(RuntimeOrigin::signed(1), 0, 7, VotingMode::Open),
+			crate::Error::<Test>::MaxMemberProposalNumber
+		);
+
+		assert_ok!(MultisigModule::add_member(RuntimeOrigin::signed(2), 0, 7, VotingMode::Open));
+		assert_ok!(MultisigModule::add_member(RuntimeOrigin::signed(2), 0, 8, VotingMode::Open));
+		assert_eq!(MultisigModule::group_proposals(0), 5);
+		assert_noop!(
+			MultisigModule::add_member(RuntimeOrigin::signed(3), 0, 9, VotingMode::Open),
+			crate::Error::<Test>::MaxProposalNumber
+		);
+
+		// finishing a proposal frees a place for its owner
+		assert_ok!(MultisigModule::cancel_proposal(RuntimeOrigin::signed(1), 1));
+		assert_eq!(MultisigModule::member_proposals(0, 1), 2);
+		assert_eq!(MultisigModule::group_proposals(0), 4);
+		assert_ok!(MultisigModule::add_member(RuntimeOrigin::signed(1), 0, 7, VotingMode::Open));
+	});
+}
+
+#[test]
+fn amend_proposal_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(MultisigModule::create_multisig_group(
+			RuntimeOrigin::signed(1),
+			vec![1, 2, 3],
+			b"board".to_vec()
+		));
+		assert_ok!(MultisigModule::add_member(RuntimeOrigin::signed(1), 0, 4, VotingMode::Open));
+
+		assert_noop!(
+			MultisigModule::amend_proposal(RuntimeOrigin::signed(2), 1, ProposalPayload::Member(5)),
+			crate::Error::<Test>::NotProposalOwner
+		);
+		assert_noop!(
+			MultisigModule::amend_proposal(
+				RuntimeOrigin::signed(1),
+				1,
+				ProposalPayload::Swap(2, 5)
+			),
+			crate::Error::<Test>::InvalidAmendment
+		);
+
+		run_to_block(3);
+		assert_ok!(MultisigModule::amend_proposal(
+			RuntimeOrigin::signed(1),
+			1,
+			ProposalPayload::Member(5)
+		));
+		System::assert_has_event(RuntimeEvent::MultisigModule(Event::ProposalAmended {
+			group_id: 0,
+			proposal_id: 1,
+			revision: 1,
+		}));
+		assert_eq!(MultisigModule::proposals(1).unwrap().revision, 1);
+		assert_eq!(MultisigModule::add_members(1), Some(5));
+		assert_eq!(MultisigModule::votings(1).unwrap().ayes, vec![1]);
+		assert_eq!(
+			MultisigModule::revisions(1),
+			vec![ProposalRevision {
+				revision: 0,
+				payload: ProposalPayload::Member(4),
+				amended_at: 3
+			}]
+		);
+
+		// the payload is fixed once another member voted
+		assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(2), 1, Weight::MAX));
+		assert_noop!(
+			MultisigModule::amend_proposal(RuntimeOrigin::signed(1), 1, ProposalPayload::Member(4)),
+			crate::Error::<Test>::AlreadyVoted
+		);
+
+		assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(3), 1, Weight::MAX));
+		assert_eq!(MultisigModule::members(0).to_vec(), vec![1, 2, 3, 5]);
+		assert_eq!(MultisigModule::finished_proposal(1).unwrap().proposal.revision, 1);
+	});
+}
+
+#[test]
+fn amend_call_proposal_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(MultisigModule::create_multisig_group(
+			RuntimeOrigin::signed(1),
+			vec![1, 2, 3],
+			b"board".to_vec()
+		));
+
+		let remark = |remark: &[u8]| {
+			Box::new(RuntimeCall::System(frame_system::Call::remark_with_event {
+				remark: remark.to_vec(),
+			}))
+		};
+		assert_ok!(MultisigModule::propose_call(
+			RuntimeOrigin::signed(1),
+			0,
+			b"remark".to_vec(),
+			None,
+			remark(b"typo"),
+			VotingMode::Open,
+			vec![]
+		));
+		let first = MultisigModule::call_proposals(1).unwrap();
+		let reserved = Balances::reserved_balance(1);
+
+		assert_ok!(MultisigModule::amend_proposal(
+			RuntimeOrigin::signed(1),
+			1,
+			ProposalPayload::Call(remark(b"multisig remark"))
+		));
+
+		// the replaced call is kept for its revision and the deposit follows the new length
+		let amended = MultisigModule::call_proposals(1).unwrap();
+		assert!(Preimage::len(&first.hash()).is_some());
+		assert!(Preimage::len(&amended.hash()).is_some());
+		assert_eq!(Balances::reserved_balance(1), reserved + 11);
+		assert_eq!(MultisigModule::revisions(1)[0].payload, ProposalPayload::Call(first.clone()));
+
+		assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(2), 1, Weight::MAX));
+		assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(3), 1, Weight::MAX));
+		System::assert_has_event(RuntimeEvent::System(frame_system::Event::Remarked {
+			sender: MultisigModule::account_id(0),
+			hash: BlakeTwo256::hash(b"multisig remark"),
+		}));
+		assert!(Preimage::len(&first.hash()).is_some());
+
+		// the call is released once the record is pruned
+		for (proposal_id, remark_of) in [(2, &b"second"[..]), (3, &b"third"[..])] {
+			assert_ok!(MultisigModule::propose_call(
+				RuntimeOrigin::signed(1),
+				0,
+				b"remark".to_vec(),
+				None,
+				remark(remark_of),
+				VotingMode::Open,
+				vec![]
+			));
+			for member in 2..=3 {
+				assert_ok!(MultisigModule::approve(
+					RuntimeOrigin::signed(member),
+					proposal_id,
+					Weight::MAX
+				));
+			}
+		}
+		run_to_block(2);
+		assert!(MultisigModule::finished_proposal(1).is_none());
+		assert!(MultisigModule::revisions(1).is_empty());
+		assert!(Preimage::len(&first.hash()).is_none());
+	});
+}
+
+#[test]
+fn sponsor_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(MultisigModule::create_multisig_group(
+			RuntimeOrigin::signed(1),
+			vec![1, 2, 3, 4],
+			b"board".to_vec()
+		));
+		assert_noop!(
+			MultisigModule::set_required_sponsors(RuntimeOrigin::signed(1), 0, 2),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(MultisigModule::set_required_sponsors(
+			RuntimeOrigin::signed(MultisigModule::account_id(0)),
+			0,
+			2
+		));
+
+		assert_ok!(MultisigModule::add_member(RuntimeOrigin::signed(1), 0, 5, VotingMode::Open));
+		assert_eq!(MultisigModule::proposals(1).unwrap().status, ProposalStatus::Draft);
+		assert!(MultisigModule::votings(1).unwrap().ayes.is_empty());
+		assert_noop!(
+			MultisigModule::approve(RuntimeOrigin::signed(2), 1, Weight::MAX),
+			crate::Error::<Test>::DraftProposal
+		);
+		assert_noop!(
+			MultisigModule::sponsor(RuntimeOrigin::signed(1), 1),
+			crate::Error::<Test>::AlreadySponsored
+		);
+
+		assert_ok!(MultisigModule::sponsor(RuntimeOrigin::signed(2), 1));
+		assert_noop!(
+			MultisigModule::sponsor(RuntimeOrigin::signed(2), 1),
+			crate::Error::<Test>::AlreadySponsored
+		);
+
+		run_to_block(5);
+		assert_ok!(MultisigModule::sponsor(RuntimeOrigin::signed(3), 1));
+		System::assert_has_event(RuntimeEvent::MultisigModule(Event::ProposalOpened {
+			group_id: 0,
+			proposal_id: 1,
+			end: 15,
+		}));
+		assert_eq!(MultisigModule::proposals(1).unwrap().status, ProposalStatus::Pending);
+		assert_eq!(MultisigModule::votings(1).unwrap().ayes, vec![1]);
+		assert!(MultisigModule::sponsors(1).is_empty());
+		assert_noop!(
+			MultisigModule::sponsor(RuntimeOrigin::signed(4), 1),
+			crate::Error::<Test>::NotDraft
+		);
+
+		for member in 2..=4 {
+			assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(member), 1, Weight::MAX));
+		}
+		assert_eq!(MultisigModule::members(0).to_vec(), vec![1, 2, 3, 4, 5]);
+
+		// the owner and the sponsors are members of the group
+		assert_noop!(
+			MultisigModule::set_required_sponsors(
+				RuntimeOrigin::signed(MultisigModule::account_id(0)),
+				0,
+				5
+			),
+			crate::Error::<Test>::TooManySponsors
+		);
+		assert_ok!(MultisigModule::set_required_sponsors(
+			RuntimeOrigin::signed(MultisigModule::account_id(0)),
+			0,
+			4
+		));
+
+		// a draft which is not sponsored within the voting period expires
+		assert_ok!(MultisigModule::remove_member(RuntimeOrigin::signed(1), 0, 5, VotingMode::Open));
+		assert_ok!(MultisigModule::sponsor(RuntimeOrigin::signed(2), 2));
+		run_to_block(15);
+		assert_eq!(MultisigModule::proposals(2).unwrap().status, ProposalStatus::Draft);
+		run_to_block(16);
+		assert!(MultisigModule::proposals(2).is_none());
+		assert_eq!(MultisigModule::finished_proposal(2).unwrap().outcome, ProposalOutcome::Expired);
+		assert!(MultisigModule::sponsors(2).is_empty());
+		assert_eq!(MultisigModule::missed_proposals(0, 3), 0);
+	});
+}
+
+#[test]
+fn swapped_sponsor_is_kept() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(MultisigModule::create_multisig_group(
+			RuntimeOrigin::signed(1),
+			vec![1, 2, 3, 4],
+			b"board".to_vec()
+		));
+		assert_ok!(MultisigModule::set_required_sponsors(
+			RuntimeOrigin::signed(MultisigModule::account_id(0)),
+			0,
+			2
+		));
+
+		assert_ok!(MultisigModule::add_member(RuntimeOrigin::signed(1), 0, 5, VotingMode::Open));
+		assert_ok!(MultisigModule::sponsor(RuntimeOrigin::signed(2), 1));
+
+		assert_ok!(MultisigModule::swap_member(
+			RuntimeOrigin::signed(1),
+			0,
+			2,
+			6,
+			VotingMode::Open
+		));
+		assert_ok!(MultisigModule::sponsor(RuntimeOrigin::signed(3), 2));
+		assert_ok!(MultisigModule::sponsor(RuntimeOrigin::signed(4), 2));
+		assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(3), 2, Weight::MAX));
+		assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(4), 2, Weight::MAX));
+		assert_eq!(MultisigModule::members(0).to_vec(), vec![1, 3, 4, 6]);
+
+		// the sponsorship moves to the new member
+		assert_eq!(MultisigModule::sponsors(1), vec![6]);
+		assert_noop!(
+			MultisigModule::sponsor(RuntimeOrigin::signed(6), 1),
+			crate::Error::<Test>::AlreadySponsored
+		);
+		assert_ok!(MultisigModule::sponsor(RuntimeOrigin::signed(3), 1));
+		assert_eq!(MultisigModule::proposals(1).unwrap().status, ProposalStatus::Pending);
+	});
+}
+
+#[test]
+fn threshold_table_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(MultisigModule::create_multisig_group(
+			RuntimeOrigin::signed(1),
+			vec![1, 2, 3, 4, 5],
+			b"board".to_vec()
+		));
+
+		assert_eq!(
+			MultisigModule::threshold_of(0, ProposalType::RemoveMember),
+			ProposalThreshold::All
+		);
+		assert_eq!(
+			MultisigModule::threshold_of(0, ProposalType::FreezeGroup),
+			ProposalThreshold::MoreThanhalf
+		);
+
+		assert_noop!(
+			MultisigModule::set_threshold(
+				RuntimeOrigin::signed(1),
+				0,
+				ProposalType::Call,
+				Some(ProposalThreshold::All)
+			),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(MultisigModule::set_threshold(
+			RuntimeOrigin::signed(MultisigModule::account_id(0)),
+			0,
+			ProposalType::Call,
+			Some(ProposalThreshold::All)
+		));
+		System::assert_has_event(RuntimeEvent::MultisigModule(Event::ThresholdSet {
+			group_id: 0,
+			proposaltype: ProposalType::Call,
+			threshold: ProposalThreshold::All,
+		}));
+
+		let call = Box::new(RuntimeCall::System(frame_system::Call::remark_with_event {
+			remark: b"multisig".to_vec(),
+		}));
+		assert_ok!(MultisigModule::propose_call(
+			RuntimeOrigin::signed(1),
+			0,
+			b"remark".to_vec(),
+			None,
+			call,
+			VotingMode::Open,
+			vec![]
+		));
+		assert_eq!(MultisigModule::proposals(1).unwrap().threshold, ProposalThreshold::All);
+
+		// four of five approvals were enough with the dynamic threshold of the group
+		for member in 2..=4 {
+			assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(member), 1, Weight::MAX));
+		}
+		assert!(MultisigModule::proposals(1).is_some());
+		assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(5), 1, Weight::MAX));
+		assert!(MultisigModule::proposals(1).is_none());
+
+		assert_ok!(MultisigModule::set_threshold(
+			RuntimeOrigin::signed(MultisigModule::account_id(0)),
+			0,
+			ProposalType::Call,
+			None
+		));
+		assert_eq!(
+			MultisigModule::threshold_of(0, ProposalType::Call),
+			ProposalThreshold::MoreThanhalf
+		);
+	});
+}
+
+#[test]
+fn missed_proposals_open_a_removal() {
+	new_test_ext().execute_with(|| {
+		MaxMissedProposals::set(3);
+		assert_ok!(MultisigModule::create_multisig_group(
+			RuntimeOrigin::signed(1),
+			vec![1, 2, 3, 4, 5],
+			b"board".to_vec()
+		));
+
+		// 5 never votes
+		for proposal_id in 1..=3 {
+			assert_ok!(MultisigModule::remove_member(
+				RuntimeOrigin::signed(1),
+				0,
+				4,
+				VotingMode::Open
+			));
+			assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(4), proposal_id, Weight::MAX));
+			assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(3), proposal_id, Weight::MAX));
+			assert_ok!(MultisigModule::reject(RuntimeOrigin::signed(2), proposal_id));
+		}
+		assert_eq!(MultisigModule::missed_proposals(0, 5), 3);
+		assert_eq!(MultisigModule::missed_proposals(0, 4), 0);
+
+		run_to_block(2);
+		System::assert_has_event(RuntimeEvent::MultisigModule(Event::InactiveMember {
+			group_id: 0,
+			member: 5,
+			proposal_id: 4,
+		}));
+		assert_eq!(MultisigModule::missed_proposals(0, 5), 0);
+
+		// 5 is not counted, the approvals of the four other members are enough
+		for member in 1..=4 {
+			assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(member), 4, Weight::MAX));
+		}
+		assert_eq!(MultisigModule::members(0).to_vec(), vec![1, 2, 3, 4]);
+		assert_eq!(MultisigModule::last_active(0, 5), None);
+	});
+}
+
+#[test]
+fn inactivity_period_opens_a_removal() {
+	new_test_ext().execute_with(|| {
+		InactivityPeriod::set(20);
+		assert_ok!(MultisigModule::create_multisig_group(
+			RuntimeOrigin::signed(1),
+			vec![1, 2, 3, 4],
+			b"board".to_vec()
+		));
+
+		run_to_block(15);
+		assert_ok!(MultisigModule::add_member(RuntimeOrigin::signed(1), 0, 5, VotingMode::Open));
+		assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(4), 1, Weight::MAX));
+		assert_ok!(MultisigModule::reject(RuntimeOrigin::signed(2), 1));
+
+		run_to_block(22);
+		System::assert_has_event(RuntimeEvent::MultisigModule(Event::InactiveMember {
+			group_id: 0,
+			member: 3,
+			proposal_id: 2,
+		}));
+		assert_eq!(MultisigModule::last_active(0, 3), Some(22));
+		assert_eq!(MultisigModule::pending_removals(0, 3), 1);
+		assert_eq!(MultisigModule::inactivity_cursor(), None);
+
+		// a single removal is pending at a time
+		run_to_block(23);
+		assert_eq!(MultisigModule::proposal_count(), 2);
+
+		assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(1), 2, Weight::MAX));
+		assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(2), 2, Weight::MAX));
+		assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(4), 2, Weight::MAX));
+		assert_eq!(MultisigModule::members(0).to_vec(), vec![1, 2, 4]);
+		assert_eq!(MultisigModule::pending_removals(0, 3), 0);
+	});
+}
+
+#[test]
+fn members_stay_above_the_minimum() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			MultisigModule::create_multisig_group(
+				RuntimeOrigin::signed(1),
+				vec![1, 2],
+				b"board".to_vec()
+			),
+			crate::Error::<Test>::MinMultisigNumber
+		);
+		assert_ok!(MultisigModule::create_multisig_group(
+			RuntimeOrigin::signed(1),
+			vec![1, 2, 3, 4],
+			b"board".to_vec()
+		));
+
+		assert_ok!(MultisigModule::remove_member(RuntimeOrigin::signed(1), 0, 4, VotingMode::Open));
+		assert_ok!(MultisigModule::remove_member(RuntimeOrigin::signed(1), 0, 3, VotingMode::Open));
+		for member in 2..=4 {
+			assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(member), 1, Weight::MAX));
+		}
+		assert_eq!(MultisigModule::members(0).to_vec(), vec![1, 2, 3]);
+
+		assert_noop!(
+			MultisigModule::remove_member(RuntimeOrigin::signed(2), 0, 1, VotingMode::Open),
+			crate::Error::<Test>::MinMultisigNumber
+		);
+
+		// the removal proposed earlier can not be executed anymore
+		assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(2), 2, Weight::MAX));
+		assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(3), 2, Weight::MAX));
+		assert_eq!(
+			MultisigModule::finished_proposal(2).unwrap().outcome,
+			ProposalOutcome::ExecutionFailed(crate::Error::<Test>::MinMultisigNumber.into())
+		);
+		assert_eq!(MultisigModule::members(0).to_vec(), vec![1, 2, 3]);
+	});
+}
+
+#[test]
+fn dissolve_group_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(MultisigModule::create_multisig_group(
+			RuntimeOrigin::signed(1),
+			vec![1, 2, 3, 4, 5],
+			b"board".to_vec()
+		));
+		let _ = Balances::deposit_creating(&MultisigModule::account_id(0), 101);
+
+		let call = Box::new(RuntimeCall::System(frame_system::Call::remark_with_event {
+			remark: b"multisig".to_vec(),
+		}));
+		assert_ok!(MultisigModule::propose_call(
+			RuntimeOrigin::signed(2),
+			0,
+			b"remark".to_vec(),
+			None,
+			call,
+			VotingMode::Open,
+			vec![]
+		));
+		let bounded = MultisigModule::call_proposals(1).unwrap();
+
+		assert_noop!(
+			MultisigModule::dissolve_group(RuntimeOrigin::signed(1), 0, vec![], VotingMode::Open),
+			crate::Error::<Test>::NoBeneficiary
+		);
+		assert_noop!(
+			MultisigModule::set_threshold(
+				RuntimeOrigin::signed(MultisigModule::account_id(0)),
+				0,
+				ProposalType::DissolveGroup,
+				Some(ProposalThreshold::MoreThanhalf)
+			),
+			crate::Error::<Test>::FixedThreshold
+		);
+		assert_ok!(MultisigModule::dissolve_group(
+			RuntimeOrigin::signed(1),
+			0,
+			vec![4, 5],
+			VotingMode::Open
+		));
+
+		// four of five approvals are not enough
+		for member in 2..=4 {
+			assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(member), 2, Weight::MAX));
+		}
+		assert!(MultisigModule::proposals(2).is_some());
+		assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(5), 2, Weight::MAX));
+
+		System::assert_has_event(RuntimeEvent::MultisigModule(Event::GroupDissolved {
+			group_id: 0,
+			proposal_id: 2,
+			funds: 101,
+		}));
+		assert_eq!(Balances::free_balance(MultisigModule::account_id(0)), 0);
+		assert_eq!(Balances::free_balance(4), 151);
+		assert_eq!(Balances::free_balance(5), 150);
+
+		assert!(MultisigModule::members(0).is_empty());
+		assert!(MultisigModule::proposals(1).is_none());
+		assert!(MultisigModule::votings(1).is_none());
+		assert!(Preimage::len(&bounded.hash()).is_none());
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert_eq!(MultisigModule::finished_queue(), vec![2]);
+		assert!(MultisigModule::groups(0).is_none());
+		assert!(MultisigModule::groups_of(1).is_empty());
+
+		// the id of the dissolved group is not reused
+		assert_ok!(MultisigModule::create_multisig_group(
+			RuntimeOrigin::signed(1),
+			vec![1, 2, 3],
+			b"board".to_vec()
+		));
+		assert_eq!(MultisigModule::groups_of(1), vec![1]);
+	});
+}
+
+#[test]
+fn named_groups_work() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			MultisigModule::create_multisig_group(
+				RuntimeOrigin::signed(1),
+				vec![1, 2, 3],
+				vec![0; 17]
+			),
+			crate::Error::<Test>::GroupNameTooLong
+		);
+		assert_ok!(MultisigModule::create_multisig_group(
+			RuntimeOrigin::signed(1),
+			vec![1, 2, 3],
+			b"board".to_vec()
+		));
+
+		run_to_block(3);
+		assert_ok!(MultisigModule::create_multisig_group(
+			RuntimeOrigin::signed(4),
+			vec![2, 4, 5],
+			b"treasury".to_vec()
+		));
+		System::assert_last_event(RuntimeEvent::MultisigModule(Event::CreateMultisig {
+			group_id: 1,
+			who: 4,
+			dyn_threshold: 3,
+		}));
+		assert_eq!(
+			MultisigModule::group_info(1),
+			Some(GroupInfo { name: b"treasury".to_vec(), creator: 4, created_at: 3 })
+		);
+		assert_eq!(MultisigModule::groups_of(2), vec![0, 1]);
+		assert_eq!(MultisigModule::groups_of(4), vec![1]);
+		assert_ne!(MultisigModule::account_id(0), MultisigModule::account_id(1));
+
+		// the groups vote apart
+		assert_ok!(MultisigModule::add_member(RuntimeOrigin::signed(4), 1, 3, VotingMode::Open));
+		assert_noop!(
+			MultisigModule::approve(RuntimeOrigin::signed(1), 1, Weight::MAX),
+			crate::Error::<Test>::MustContainCaller
+		);
+		assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(2), 1, Weight::MAX));
+		assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(5), 1, Weight::MAX));
+		assert_eq!(MultisigModule::members(1).to_vec(), vec![2, 3, 4, 5]);
+		assert_eq!(MultisigModule::members(0).to_vec(), vec![1, 2, 3]);
+		assert_eq!(MultisigModule::groups_of(3), vec![0, 1]);
+
+		// the membership hook only follows the primary group
+		assert!(MembersChanged::get().is_empty());
+	});
+}
+
+#[test]
+fn group_deposit_and_limit_work() {
+	new_test_ext().execute_with(|| {
+		GroupDeposit::set(10);
+		MaxGroupNumber::set(2);
+
+		assert_ok!(MultisigModule::create_multisig_group(
+			RuntimeOrigin::signed(1),
+			vec![1, 2, 3],
+			b"board".to_vec()
+		));
+		assert_ok!(MultisigModule::create_multisig_group(
+			RuntimeOrigin::signed(4),
+			vec![3, 4, 5],
+			b"treasury".to_vec()
+		));
+		assert_eq!(Balances::reserved_balance(1), 10);
+		assert_eq!(MultisigModule::group_deposit(1), Some((4, 10)));
+		assert_noop!(
+			MultisigModule::create_multisig_group(
+				RuntimeOrigin::signed(2),
+				vec![1, 2, 3],
+				b"audit".to_vec()
+			),
+			crate::Error::<Test>::MaxGroupNumber
+		);
+
+		// dissolving a group returns the deposit and frees its place
+		assert_ok!(MultisigModule::dissolve_group(
+			RuntimeOrigin::signed(4),
+			1,
+			vec![4],
+			VotingMode::Open
+		));
+		for member in [3, 5] {
+			assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(member), 1, Weight::MAX));
+		}
+		assert!(MultisigModule::groups(1).is_none());
+		assert_eq!(Balances::reserved_balance(4), 0);
+		assert_eq!(MultisigModule::group_deposit(1), None);
+
+		assert_ok!(MultisigModule::create_multisig_group(
+			RuntimeOrigin::signed(2),
+			vec![1, 2, 3],
+			b"audit".to_vec()
+		));
+		assert_eq!(Balances::reserved_balance(2), 10);
+	});
+}
+
+#[test]
+fn migrate_to_v1_work() {
+	use crate::migrations::v1::{old, MigrateToV1};
+	use frame_support::{
+		storage::unhashed,
+		traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+	};
+
+	new_test_ext().execute_with(|| {
+		// the layout of version 0, the only group and its add and remove member proposals
+		StorageVersion::new(0).put::<MultisigModule>();
+		old::MultisigMembers::<Test, ()>::put(
+			frame_support::BoundedVec::try_from(vec![1u128, 2, 3]).unwrap(),
+		);
+		let proposal = |proposal_id, status, vote, proposaltype| old::Proposal::<Test> {
+			proposal_id,
+			threshold: ProposalThreshold::All,
+			status,
+			vote,
+			proposaltype,
+			owner: 1,
+		};
+		let votes = |index, ayes| old::Votes::<Test> { index, threshold: 3, ayes, nays: vec![] };
+		// the pending proposal reused the id of the first finished one
+		unhashed::put(
+			&crate::Proposals::<Test>::hashed_key_for(1),
+			&proposal(1, ProposalStatus::Pending, 1, ProposalType::RemoveMember),
+		);
+		unhashed::put(&crate::Voting::<Test>::hashed_key_for(1), &votes(1, vec![1]));
+		crate::RemoveMember::<Test>::insert(1, 3);
+		unhashed::put(
+			&crate::FinishedProposal::<Test>::hashed_key_for(1),
+			&proposal(1, ProposalStatus::Finished, 3, ProposalType::AddMember),
+		);
+		unhashed::put(
+			&crate::FinishedProposal::<Test>::hashed_key_for(2),
+			&proposal(2, ProposalStatus::Finished, 3, ProposalType::AddMember),
+		);
+		unhashed::put(&crate::Voting::<Test>::hashed_key_for(2), &votes(2, vec![1, 2, 3]));
+
+		MigrateToV1::<Test>::on_runtime_upgrade();
+
+		assert_eq!(MultisigModule::on_chain_storage_version(), 1);
+		assert_eq!(MultisigModule::members(0).to_vec(), vec![1, 2, 3]);
+		assert_eq!(MultisigModule::groups_of(2), vec![0]);
+		assert_eq!(MultisigModule::group_count(), 1);
+		assert_eq!(MultisigModule::primary_group(), Some(0));
+		assert_eq!(
+			MultisigModule::groups(0).map(|info| info.creator),
+			Some(MultisigModule::account_id(0))
+		);
+
+		let proposal = MultisigModule::proposals(1).unwrap();
+		assert_eq!(proposal.group, 0);
+		assert_eq!(proposal.proposaltype, ProposalType::RemoveMember);
+		assert_eq!(proposal.tally, TallyMode::HeadCount);
+		assert_eq!(proposal.voting_mode, VotingMode::Open);
+		assert_eq!(MultisigModule::group_proposals(0), 1);
+		assert_eq!(MultisigModule::member_proposals(0, 1), 1);
+		assert_eq!(MultisigModule::pending_removals(0, 3), 1);
+		let votes = MultisigModule::votings(1).unwrap();
+		assert_eq!(votes.ayes, vec![1]);
+		assert_eq!(votes.end, 11);
+		assert_eq!(votes.commit_end, None);
+
+		let record = MultisigModule::finished_proposal(2).unwrap();
+		assert_eq!(record.proposal.group, 0);
+		assert_eq!(record.outcome, ProposalOutcome::Executed(Ok(())));
+		// the record sharing its id with the pending proposal is not pruned
+		assert_eq!(MultisigModule::finished_queue(), vec![2]);
+		assert_eq!(MultisigModule::proposal_count(), 2);
+
+		// the group votes on the migrated proposal
+		assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(2), 1, Weight::MAX));
+
+		// a second run is a no-op
+		MigrateToV1::<Test>::on_runtime_upgrade();
+		assert_eq!(MultisigModule::groups_of(2), vec![0]);
+	});
+}
+
+fn create_joint_groups() {
+	assert_ok!(MultisigModule::create_multisig_group(
+		RuntimeOrigin::signed(1),
+		vec![1, 2, 3],
+		b"company a".to_vec()
+	));
+	assert_ok!(MultisigModule::create_multisig_group(
+		RuntimeOrigin::signed(4),
+		vec![3, 4, 5],
+		b"company b".to_vec()
+	));
+}
+
+#[test]
+fn joint_proposal_work() {
+	new_test_ext().execute_with(|| {
+		create_joint_groups();
+
+		let call = Box::new(RuntimeCall::System(frame_system::Call::remark_with_event {
+			remark: b"joint venture".to_vec(),
+		}));
+		let groups = vec![(0, ProposalThreshold::All), (1, ProposalThreshold::MoreThanhalf)];
+
+		assert_ok!(MultisigModule::propose_joint_call(
+			RuntimeOrigin::signed(1),
+			groups.clone(),
+			b"move funds".to_vec(),
+			None,
+			call
+		));
+		// the proposal is created with the joint threshold of its first group
+		assert!(System::events().iter().any(|record| matches!(
+			record.event,
+			RuntimeEvent::MultisigModule(Event::CreateProposal {
+				proposal_id: 1,
+				threshold: ProposalThreshold::All,
+				..
+			})
+		)));
+		assert_eq!(MultisigModule::proposals(1).unwrap().threshold, ProposalThreshold::All);
+		let account = MultisigModule::joint_account_id(vec![1, 0]);
+		assert_ne!(account, MultisigModule::account_id(0));
+		assert_ne!(account, MultisigModule::account_id(1));
+		System::assert_has_event(RuntimeEvent::MultisigModule(Event::JointProposalCreated {
+			proposal_id: 1,
+			groups,
+			account,
+		}));
+
+		// the member of both groups is counted in each of them
+		assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(2), 1, Weight::MAX));
+		assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(3), 1, Weight::MAX));
+		System::assert_last_event(RuntimeEvent::MultisigModule(Event::JointVoteTallied {
+			proposal_id: 1,
+			tallies: vec![
+				GroupTally { group_id: 0, ayes: 3, nays: 0, members: 3, threshold: 3 },
+				GroupTally { group_id: 1, ayes: 1, nays: 0, members: 3, threshold: 3 },
+			],
+		}));
+		assert!(MultisigModule::proposals(1).is_some());
+
+		assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(4), 1, Weight::MAX));
+		assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(5), 1, Weight::MAX));
+
+		System::assert_has_event(RuntimeEvent::System(frame_system::Event::Remarked {
+			sender: account,
+			hash: BlakeTwo256::hash(b"joint venture"),
+		}));
+		assert_eq!(
+			MultisigModule::finished_proposal(1).unwrap().outcome,
+			ProposalOutcome::Executed(Ok(()))
+		);
+	});
+}
+
+#[test]
+fn joint_proposal_rejected_by_one_group() {
+	new_test_ext().execute_with(|| {
+		create_joint_groups();
+
+		let call = Box::new(RuntimeCall::System(frame_system::Call::remark { remark: vec![] }));
+		let propose = |who: u128, groups: Vec<(u32, ProposalThreshold)>| {
+			MultisigModule::propose_joint_call(
+				RuntimeOrigin::signed(who),
+				groups,
+				b"joint".to_vec(),
+				None,
+				call.clone(),
+			)
+		};
+		let half = ProposalThreshold::MoreThanhalf;
+
+		assert_noop!(propose(1, vec![(0, half)]), crate::Error::<Test>::InvalidJointGroups);
+		assert_noop!(
+			propose(1, vec![(0, half), (0, half)]),
+			crate::Error::<Test>::InvalidJointGroups
+		);
+		assert_noop!(
+			propose(1, vec![(0, half), (7, half)]),
+			crate::Error::<Test>::InvalidJointGroups
+		);
+		assert_noop!(propose(4, vec![(0, half), (1, half)]), crate::Error::<Test>::NotFoundAccount);
+
+		assert_ok!(propose(1, vec![(0, half), (1, half)]));
+		assert_noop!(
+			MultisigModule::approve(RuntimeOrigin::signed(6), 1, Weight::MAX),
+			crate::Error::<Test>::MustContainCaller
+		);
+
+		// company b can not reach its threshold anymore
+		assert_ok!(MultisigModule::reject(RuntimeOrigin::signed(5), 1));
+		System::assert_has_event(RuntimeEvent::MultisigModule(Event::JointVoteTallied {
+			proposal_id: 1,
+			tallies: vec![
+				GroupTally { group_id: 0, ayes: 1, nays: 0, members: 3, threshold: 3 },
+				GroupTally { group_id: 1, ayes: 0, nays: 1, members: 3, threshold: 3 },
+			],
+		}));
+		assert_eq!(
+			MultisigModule::finished_proposal(1).unwrap().outcome,
+			ProposalOutcome::Rejected
+		);
+		assert_eq!(Balances::reserved_balance(1), 0);
+	});
+}
+
+#[test]
+fn events_are_indexed() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(MultisigModule::create_multisig_group(
+			RuntimeOrigin::signed(1),
+			vec![1, 2, 3],
+			b"board".to_vec()
+		));
+		assert_ok!(MultisigModule::add_member(RuntimeOrigin::signed(1), 0, 4, VotingMode::Open));
+
+		let group = MultisigModule::account_topic(&MultisigModule::account_id(0));
+		let proposal = MultisigModule::proposal_hash(1);
+		let member = MultisigModule::account_topic(&1);
+		let record = System::events().pop().unwrap();
+		assert!(matches!(
+			record.event,
+			RuntimeEvent::MultisigModule(Event::CreateProposal { proposal_id: 1, .. })
+		));
+		assert_eq!(record.topics, vec![group, proposal, member]);
+
+		assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(2), 1, Weight::MAX));
+		assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(3), 1, Weight::MAX));
+
+		// a client follows the proposal through its topic only
+		let followed = System::event_topics(proposal)
+			.into_iter()
+			.map(|(_, index)| System::events()[index as usize].event.clone())
+			.collect::<Vec<_>>();
+		assert!(followed.contains(&RuntimeEvent::MultisigModule(Event::FinishedProposal {
+			group_id: 0,
+			proposal_id: 1,
+			vote: 3,
+			outcome: ProposalOutcome::Executed(Ok(())),
+		})));
+		assert!(followed.iter().all(|event| !matches!(
+			event,
+			RuntimeEvent::MultisigModule(Event::ChangeGroup { .. })
+		)));
+		assert_eq!(System::event_topics(MultisigModule::account_topic(&4)).len(), 1);
+		assert_ne!(MultisigModule::proposal_hash(1), MultisigModule::proposal_hash(2));
+	});
+}
+
+fn eth_key(seed: u8) -> (ecdsa::Pair, H160) {
+	let pair = ecdsa::Pair::from_seed(&[seed; 32]);
+	let digest = [seed; 32];
+	let public =
+		sp_io::crypto::secp256k1_ecdsa_recover(&pair.sign_prehashed(&digest).0, &digest).unwrap();
+
+	(pair, H160::from_slice(&sp_io::hashing::keccak_256(&public)[12..]))
+}
+
+// the EIP-191 signature of the vote, as a wallet produces it
+fn eth_sign(pair: &ecdsa::Pair, proposal_id: u32, revision: u32, approve: bool) -> [u8; 65] {
+	let mut message = b"\x19Ethereum Signed Message:\n32".to_vec();
+	message.extend(MultisigModule::eth_vote_hash(proposal_id, revision, approve));
+
+	pair.sign_prehashed(&sp_io::hashing::keccak_256(&message)).0
+}
+
+#[test]
+fn approve_with_eth_work() {
+	new_test_ext().execute_with(|| {
+		let (pair, address) = eth_key(7);
+		let (other, _) = eth_key(8);
+		let eth = MultisigModule::eth_account_id(address);
+		assert_ok!(MultisigModule::create_multisig_group(
+			RuntimeOrigin::signed(1),
+			vec![1, 2, eth],
+			b"board".to_vec()
+		));
+		assert_ok!(MultisigModule::add_member(RuntimeOrigin::signed(1), 0, 4, VotingMode::Open));
+
+		let validate = |approve: bool, signature: [u8; 65]| {
+			<MultisigModule as ValidateUnsigned>::validate_unsigned(
+				TransactionSource::External,
+				&crate::Call::approve_with_eth {
+					proposal_id: 1,
+					approve,
+					signer: address,
+					signature,
+					proposal_weight_bound: Weight::MAX,
+				},
+			)
+		};
+		let bad_proof: TransactionValidity = Err(InvalidTransaction::BadProof.into());
+
+		// signed by another key or for the other vote
+		assert_eq!(validate(true, eth_sign(&other, 1, 0, true)), bad_proof);
+		assert_eq!(validate(true, eth_sign(&pair, 1, 0, false)), bad_proof);
+		assert_noop!(
+			MultisigModule::approve_with_eth(
+				RuntimeOrigin::none(),
+				1,
+				true,
+				address,
+				eth_sign(&pair, 1, 0, false),
+				Weight::MAX
+			),
+			crate::Error::<Test>::InvalidEthSignature
+		);
+
+		let signature = eth_sign(&pair, 1, 0, true);
+		assert!(validate(true, signature).is_ok());
+		assert_noop!(
+			MultisigModule::approve_with_eth(
+				RuntimeOrigin::signed(1),
+				1,
+				true,
+				address,
+				signature,
+				Weight::MAX
+			),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(MultisigModule::approve_with_eth(
+			RuntimeOrigin::none(),
+			1,
+			true,
+			address,
+			signature,
+			Weight::MAX
+		));
+		System::assert_last_event(RuntimeEvent::MultisigModule(Event::EthVoteCast {
+			group_id: 0,
+			proposal_id: 1,
+			signer: address,
+			who: eth,
+			approve: true,
+		}));
+		assert_eq!(MultisigModule::votings(1).unwrap().ayes, vec![1, eth]);
+		assert_eq!(Balances::total_balance(&eth), 0);
+
+		// a signature is only counted once
+		assert_eq!(validate(true, signature), bad_proof);
+		assert_noop!(
+			MultisigModule::approve_with_eth(
+				RuntimeOrigin::none(),
+				1,
+				true,
+				address,
+				signature,
+				Weight::MAX
+			),
+			crate::Error::<Test>::RepeatVoting
+		);
+
+		assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(2), 1, Weight::MAX));
+		assert!(MultisigModule::members(0).contains(&4));
+	});
+}
+
+#[test]
+fn eth_vote_is_bound_to_the_chain_and_the_payload() {
+	new_test_ext().execute_with(|| {
+		let (pair, address) = eth_key(7);
+		let eth = MultisigModule::eth_account_id(address);
+		assert_ok!(MultisigModule::create_multisig_group(
+			RuntimeOrigin::signed(1),
+			vec![1, 2, eth],
+			b"board".to_vec()
+		));
+		assert_ok!(MultisigModule::add_member(RuntimeOrigin::signed(1), 0, 4, VotingMode::Open));
+		let signature = eth_sign(&pair, 1, 0, true);
+		let vote = || {
+			MultisigModule::approve_with_eth(
+				RuntimeOrigin::none(),
+				1,
+				true,
+				address,
+				signature,
+				Weight::MAX,
+			)
+		};
+
+		// the same proposal on a chain with another genesis
+		let genesis = System::block_hash(0);
+		frame_system::BlockHash::<Test>::insert(0, H256::repeat_byte(1));
+		assert_noop!(vote(), crate::Error::<Test>::InvalidEthSignature);
+		frame_system::BlockHash::<Test>::insert(0, genesis);
+
+		// the same proposal with another payload
+		crate::AddMember::<Test>::insert(1, 5);
+		assert_noop!(vote(), crate::Error::<Test>::InvalidEthSignature);
+		crate::AddMember::<Test>::insert(1, 4);
+
+		assert_ok!(vote());
+	});
+}
+
+#[test]
+fn rejected_eth_vote_is_not_replayed() {
+	new_test_ext().execute_with(|| {
+		let (pair, address) = eth_key(7);
+		let eth = MultisigModule::eth_account_id(address);
+		assert_ok!(MultisigModule::create_multisig_group(
+			RuntimeOrigin::signed(1),
+			vec![1, 2, eth],
+			b"board".to_vec()
+		));
+		assert_ok!(MultisigModule::add_member(RuntimeOrigin::signed(1), 0, 4, VotingMode::Open));
+		assert_ok!(MultisigModule::freeze_group(RuntimeOrigin::signed(1), 0));
+		assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(2), 2, Weight::MAX));
+		assert!(MultisigModule::frozen(0).is_some());
+
+		// the vote fails on dispatch, so it must not enter the pool again
+		let signature = eth_sign(&pair, 1, 0, true);
+		let call = crate::Call::approve_with_eth {
+			proposal_id: 1,
+			approve: true,
+			signer: address,
+			signature,
+			proposal_weight_bound: Weight::MAX,
+		};
+		for _ in 0..2 {
+			assert_eq!(
+				<MultisigModule as ValidateUnsigned>::validate_unsigned(
+					TransactionSource::External,
+					&call
+				),
+				Err(InvalidTransaction::BadProof.into())
+			);
+			assert_noop!(
+				MultisigModule::approve_with_eth(
+					RuntimeOrigin::none(),
+					1,
+					true,
+					address,
+					signature,
+					Weight::MAX
+				),
+				crate::Error::<Test>::GroupFrozen
+			);
+		}
+	});
+}
+
+#[test]
+fn token_weighted_voting_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(MultisigModule::create_multisig_group(
+			RuntimeOrigin::signed(1),
+			vec![1, 2, 3],
+			b"board".to_vec()
+		));
+		assert_noop!(
+			MultisigModule::set_tally_mode(RuntimeOrigin::signed(1), 0, TallyMode::TokenWeighted),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(MultisigModule::set_tally_mode(
+			RuntimeOrigin::signed(MultisigModule::account_id(0)),
+			0,
+			TallyMode::TokenWeighted
+		));
+		assert_ok!(MultisigModule::set_threshold(
+			RuntimeOrigin::signed(MultisigModule::account_id(0)),
+			0,
+			ProposalType::AddMember,
+			Some(ProposalThreshold::MoreThanhalf)
+		));
+		assert_noop!(
+			MultisigModule::add_member(RuntimeOrigin::signed(1), 0, 4, VotingMode::CommitReveal),
+			crate::Error::<Test>::TokenWeightedVoting
+		);
+
+		// the owner votes like the other members
+		assert_ok!(MultisigModule::add_member(RuntimeOrigin::signed(1), 0, 4, VotingMode::Open));
+		assert_ok!(MultisigModule::add_member(RuntimeOrigin::signed(1), 0, 5, VotingMode::Open));
+		assert_eq!(MultisigModule::proposals(1).unwrap().tally, TallyMode::TokenWeighted);
+		assert!(MultisigModule::votings(1).unwrap().ayes.is_empty());
+		assert_noop!(
+			MultisigModule::approve(RuntimeOrigin::signed(2), 1, Weight::MAX),
+			crate::Error::<Test>::TokenWeightedVoting
+		);
+		assert_noop!(
+			MultisigModule::vote_with_balance(RuntimeOrigin::signed(1), 1, true, 101, Weight::MAX),
+			crate::Error::<Test>::InsufficientVotingBalance
+		);
+
+		let frozen = |who: u128| Balances::balance_frozen(&MultisigFreezeId::get(), &who);
+		assert_ok!(MultisigModule::vote_with_balance(
+			RuntimeOrigin::signed(1),
+			2,
+			true,
+			30,
+			Weight::MAX
+		));
+		assert_ok!(MultisigModule::vote_with_balance(
+			RuntimeOrigin::signed(1),
+			1,
+			true,
+			100,
+			Weight::MAX
+		));
+		assert_eq!(frozen(1), 100);
+		assert_ok!(MultisigModule::vote_with_balance(
+			RuntimeOrigin::signed(2),
+			1,
+			false,
+			40,
+			Weight::MAX
+		));
+		System::assert_last_event(RuntimeEvent::MultisigModule(Event::WeightedVoteCast {
+			group_id: 0,
+			proposal_id: 1,
+			who: 2,
+			approve: false,
+			amount: 40,
+			ayes: 100,
+			nays: 40,
+			threshold: 151,
+		}));
+		assert!(MultisigModule::proposals(1).is_some());
+
+		// more than half of the balance of the members approved
+		assert_ok!(MultisigModule::vote_with_balance(
+			RuntimeOrigin::signed(3),
+			1,
+			true,
+			60,
+			Weight::MAX
+		));
+		assert!(MultisigModule::members(0).contains(&4));
+		assert_eq!((frozen(1), frozen(2), frozen(3)), (30, 0, 0));
+
+		assert_ok!(MultisigModule::cancel_proposal(RuntimeOrigin::signed(1), 2));
+		assert_eq!(frozen(1), 0);
+	});
+}
+
+#[test]
+fn swap_drops_the_weighted_votes() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(MultisigModule::create_multisig_group(
+			RuntimeOrigin::signed(1),
+			vec![1, 2, 3],
+			b"board".to_vec()
+		));
+		assert_ok!(MultisigModule::set_tally_mode(
+			RuntimeOrigin::signed(MultisigModule::account_id(0)),
+			0,
+			TallyMode::TokenWeighted
+		));
+		let frozen = |who: u128| Balances::balance_frozen(&MultisigFreezeId::get(), &who);
+
+		assert_ok!(MultisigModule::add_member(RuntimeOrigin::signed(1), 0, 4, VotingMode::Open));
+		assert_ok!(MultisigModule::vote_with_balance(
+			RuntimeOrigin::signed(2),
+			1,
+			true,
+			50,
+			Weight::MAX
+		));
+		assert_eq!(frozen(2), 50);
+
+		assert_ok!(MultisigModule::swap_member(
+			RuntimeOrigin::signed(1),
+			0,
+			2,
+			5,
+			VotingMode::Open
+		));
+		for member in [1, 3] {
+			assert_ok!(MultisigModule::vote_with_balance(
+				RuntimeOrigin::signed(member),
+				2,
+				true,
+				100,
+				Weight::MAX
+			));
+		}
+		assert_eq!(MultisigModule::members(0).to_vec(), vec![1, 3, 5]);
+
+		// the vote backed by the balance of the replaced member is dropped
+		assert!(MultisigModule::weighted_votes(1, 2).is_none());
+		assert!(MultisigModule::votings(1).unwrap().ayes.is_empty());
+		assert_eq!(MultisigModule::proposals(1).unwrap().vote, 0);
+		assert_eq!(frozen(2), 0);
+
+		// the new member votes with its own balance
+		assert_ok!(MultisigModule::vote_with_balance(
+			RuntimeOrigin::signed(5),
+			1,
+			true,
+			100,
+			Weight::MAX
+		));
+		assert_eq!(frozen(5), 100);
+	});
+}
+
+#[test]
+fn petition_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(MultisigModule::create_multisig_group(
+			RuntimeOrigin::signed(1),
+			vec![1, 2, 3],
+			b"board".to_vec()
+		));
+
+		let call = Box::new(RuntimeCall::System(frame_system::Call::remark_with_event {
+			remark: b"grant".to_vec(),
+		}));
+		assert_noop!(
+			MultisigModule::submit_petition(
+				RuntimeOrigin::signed(1),
+				0,
+				b"grant".to_vec(),
+				None,
+				call.clone()
+			),
+			crate::Error::<Test>::AlreadyMember
+		);
+		assert_ok!(MultisigModule::submit_petition(
+			RuntimeOrigin::signed(4),
+			0,
+			b"grant".to_vec(),
+			None,
+			call
+		));
+
+		let proposal = MultisigModule::proposals(1).unwrap();
+		assert_eq!((proposal.owner, proposal.status), (4, ProposalStatus::Petition));
+		assert_eq!(MultisigModule::petition_bond(1), Some((4, 10)));
+		assert!(Balances::reserved_balance(4) > 10);
+		assert!(MultisigModule::votings(1).unwrap().ayes.is_empty());
+
+		// the members adopt the petition before voting on it
+		assert_noop!(
+			MultisigModule::approve(RuntimeOrigin::signed(1), 1, Weight::MAX),
+			crate::Error::<Test>::PetitionProposal
+		);
+		assert_noop!(
+			MultisigModule::adopt_petition(RuntimeOrigin::signed(4), 1),
+			crate::Error::<Test>::MustContainCaller
+		);
+		assert_ok!(MultisigModule::adopt_petition(RuntimeOrigin::signed(2), 1));
+		assert_eq!(MultisigModule::proposals(1).unwrap().status, ProposalStatus::Pending);
+		assert_eq!(MultisigModule::votings(1).unwrap().ayes, vec![2]);
+		assert_noop!(
+			MultisigModule::adopt_petition(RuntimeOrigin::signed(3), 1),
+			crate::Error::<Test>::NotPetition
+		);
+
+		assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(1), 1, Weight::MAX));
+		assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(3), 1, Weight::MAX));
+
+		System::assert_has_event(RuntimeEvent::MultisigModule(Event::CallExecuted {
+			group_id: 0,
+			proposal_id: 1,
+			result: Ok(()),
+		}));
+		assert_eq!(MultisigModule::petition_bond(1), None);
+		assert_eq!(Balances::reserved_balance(4), 0);
+		assert_eq!(MultisigModule::member_proposals(0, 4), 0);
+	});
+}
+
+#[test]
+fn dismissed_petition_forfeits_bond() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(MultisigModule::create_multisig_group(
+			RuntimeOrigin::signed(1),
+			vec![1, 2, 3],
+			b"board".to_vec()
+		));
+
+		let call = Box::new(RuntimeCall::System(frame_system::Call::remark { remark: vec![] }));
+		for who in [4, 5] {
+			assert_ok!(MultisigModule::submit_petition(
+				RuntimeOrigin::signed(who),
+				0,
+				b"grant".to_vec(),
+				None,
+				call.clone()
+			));
+		}
+
+		// half of the bond goes to the group account
+		assert_ok!(MultisigModule::dismiss_petition(RuntimeOrigin::signed(1), 1));
+		System::assert_has_event(RuntimeEvent::MultisigModule(Event::PetitionDismissed {
+			group_id: 0,
+			proposal_id: 1,
+			who: 1,
+			forfeited: 5,
+		}));
+		assert_eq!(
+			MultisigModule::finished_proposal(1).unwrap().outcome,
+			ProposalOutcome::Dismissed
+		);
+		assert_eq!(Balances::reserved_balance(4), 0);
+		assert_eq!(Balances::free_balance(4), 95);
+		assert_eq!(Balances::free_balance(MultisigModule::account_id(0)), 5);
+
+		// the submitter can not take the bond back
+		assert_noop!(
+			MultisigModule::cancel_proposal(RuntimeOrigin::signed(5), 2),
+			crate::Error::<Test>::CannotCancelPetition
+		);
+
+		// a petition nobody adopted lapses and forfeits as a dismissed one
+		run_to_block(12);
+		System::assert_has_event(RuntimeEvent::MultisigModule(Event::PetitionLapsed {
+			group_id: 0,
+			proposal_id: 2,
+			forfeited: 5,
+		}));
+		assert_eq!(MultisigModule::finished_proposal(2).unwrap().outcome, ProposalOutcome::Expired);
+		assert_eq!(Balances::reserved_balance(5), 0);
+		assert_eq!(Balances::free_balance(5), 95);
+		assert_eq!(Balances::free_balance(MultisigModule::account_id(0)), 10);
+	});
+}
+
+#[test]
+fn call_filter_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(MultisigModule::create_multisig_group(
+			RuntimeOrigin::signed(1),
+			vec![1, 2, 3],
+			b"board".to_vec()
+		));
+		Balances::make_free_balance_be(&MultisigModule::account_id(0), 50);
+
+		// only `Balances::transfer_keep_alive`
+		let filter = vec![AllowedCall { pallet: 1, call: Some(3) }];
+		assert_ok!(MultisigModule::propose_call_filter(
+			RuntimeOrigin::signed(1),
+			0,
+			Some(filter.clone()),
+			VotingMode::Open
+		));
+		assert_eq!(MultisigModule::proposals(1).unwrap().threshold, ProposalThreshold::All);
+		assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(2), 1, Weight::MAX));
+		assert_eq!(MultisigModule::call_filter(0), None);
+		assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(3), 1, Weight::MAX));
+		System::assert_has_event(RuntimeEvent::MultisigModule(Event::CallFilterSet {
+			group_id: 0,
+			proposal_id: 1,
+			filter: Some(filter.clone()),
+		}));
+		assert_eq!(MultisigModule::call_filter(0), Some(filter));
+
+		assert_noop!(
+			MultisigModule::set_threshold(
+				RuntimeOrigin::signed(MultisigModule::account_id(0)),
+				0,
+				ProposalType::SetCallFilter,
+				Some(ProposalThreshold::MoreThanhalf)
+			),
+			crate::Error::<Test>::FixedThreshold
+		);
+
+		let remark = Box::new(RuntimeCall::System(frame_system::Call::remark { remark: vec![] }));
+		let transfer =
+			Box::new(RuntimeCall::Balances(pallet_balances::Call::transfer_keep_alive {
+				dest: 4,
+				value: 10,
+			}));
+		assert_noop!(
+			MultisigModule::propose_call(
+				RuntimeOrigin::signed(1),
+				0,
+				b"remark".to_vec(),
+				None,
+				remark.clone(),
+				VotingMode::Open,
+				vec![]
+			),
+			crate::Error::<Test>::CallNotAllowed
+		);
+		assert_ok!(MultisigModule::propose_call(
+			RuntimeOrigin::signed(1),
+			0,
+			b"transfer".to_vec(),
+			None,
+			transfer,
+			VotingMode::Open,
+			vec![]
+		));
+
+		// the filter is checked again on execution
+		assert_ok!(MultisigModule::propose_call_filter(
+			RuntimeOrigin::signed(1),
+			0,
+			Some(vec![AllowedCall { pallet: 0, call: None }]),
+			VotingMode::Open
+		));
+		assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(2), 3, Weight::MAX));
+		assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(3), 3, Weight::MAX));
+		assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(2), 2, Weight::MAX));
+		assert_ok!(MultisigModule::approve(RuntimeOrigin::signed(3), 2, Weight::MAX));
+		assert_eq!(
+			MultisigModule::finished_proposal(2).unwrap().outcome,
+			ProposalOutcome::ExecutionFailed(crate::Error::<Test>::CallNotAllowed.into())
+		);
+		assert_eq!(Balances::free_balance(4), 100);
+
+		assert_ok!(MultisigModule::propose_call(
+			RuntimeOrigin::signed(1),
+			0,
+			b"remark".to_vec(),
+			None,
+			remark,
+			VotingMode::Open,
+			vec![]
+		));
+	});
+}
+
+#[derive(Clone, Debug)]
+enum Action {
+	Create { who: u128, members: Vec<u128> },
+	AddMember { who: u128, member: u128 },
+	RemoveMember { who: u128, member: u128 },
+	Approve { who: u128, proposal_id: u32 },
+	Reject { who: u128, proposal_id: u32 },
+	Wait { blocks: u64 },
+}
+
+fn action() -> impl Strategy<Value = Action> {
+	let who = || 1..=7u128;
+	prop_oneof![
+		1 => (who(), prop::collection::vec(who(), 0..7))
+			.prop_map(|(who, members)| Action::Create { who, members }),
+		3 => (who(), who()).prop_map(|(who, member)| Action::AddMember { who, member }),
+		2 => (who(), who()).prop_map(|(who, member)| Action::RemoveMember { who, member }),
+		6 => (who(), 1..=12u32).prop_map(|(who, proposal_id)| Action::Approve { who, proposal_id }),
+		3 => (who(), 1..=12u32).prop_map(|(who, proposal_id)| Action::Reject { who, proposal_id }),
+		1 => (1..=6u64).prop_map(|blocks| Action::Wait { blocks }),
+	]
+}
+
+fn dispatch(action: &Action) -> DispatchResult {
+	match action.clone() {
+		Action::Create { who, members } => MultisigModule::create_multisig_group(
+			RuntimeOrigin::signed(who),
+			members,
+			b"board".to_vec(),
+		),
+		Action::AddMember { who, member } =>
+			MultisigModule::add_member(RuntimeOrigin::signed(who), 0, member, VotingMode::Open),
+		Action::RemoveMember { who, member } =>
+			MultisigModule::remove_member(RuntimeOrigin::signed(who), 0, member, VotingMode::Open),
+		Action::Approve { who, proposal_id } =>
+			MultisigModule::approve(RuntimeOrigin::signed(who), proposal_id, Weight::MAX)
+				.map(|_| ())
+				.map_err(|e| e.error),
+		Action::Reject { who, proposal_id } =>
+			MultisigModule::reject(RuntimeOrigin::signed(who), proposal_id),
+		Action::Wait { blocks } => {
+			run_to_block(System::block_number() + blocks);
+			Ok(())
+		},
+	}
+}
+
+fn max_members() -> usize {
+	<<Test as crate::Config>::MaxMultisigNumber as Get<u32>>::get() as usize
+}
+
+fn min_members() -> usize {
+	<<Test as crate::Config>::MinMultisigNumber as Get<u32>>::get() as usize
+}
+
+fn max_proposals() -> (usize, usize) {
+	(
+		<<Test as crate::Config>::MaxProposalNumber as Get<u32>>::get() as usize,
+		<<Test as crate::Config>::MaxMemberProposalNumber as Get<u32>>::get() as usize,
+	)
+}
+
+#[derive(Debug)]
+struct ModelProposal {
+	owner: u128,
+	add: bool,
+	member: u128,
+	ayes: Vec<u128>,
+	nays: Vec<u128>,
+	end: u64,
+}
+
+/// Reference model of the open voting of member proposals.
+#[derive(Debug, Default)]
+struct Model {
+	members: Vec<u128>,
+	proposals: BTreeMap<u32, ModelProposal>,
+	proposal_count: u32,
+	now: u64,
+}
+
+impl Model {
+	// whether the action succeeds
+	fn apply(&mut self, action: &Action) -> bool {
+		match action.clone() {
+			Action::Create { who, members } => self.create(who, members),
+			Action::AddMember { who, member } => self.propose(true, who, member),
+			Action::RemoveMember { who, member } => self.propose(false, who, member),
+			Action::Approve { who, proposal_id } => self.vote(who, proposal_id, true),
+			Action::Reject { who, proposal_id } => self.vote(who, proposal_id, false),
+			Action::Wait { blocks } => {
+				for _ in 0..blocks {
+					self.now += 1;
+					let now = self.now;
+					self.proposals.retain(|_, proposal| proposal.end >= now);
+				}
+				true
+			},
+		}
+	}
+
+	// the member proposals need the approvals of all of the members
+	fn threshold(&self) -> u32 {
+		self.members.len() as u32
+	}
+
+	fn create(&mut self, who: u128, mut members: Vec<u128>) -> bool {
+		let count = members.len();
+		members.sort();
+		members.dedup();
+
+		let valid = members.contains(&who) &&
+			members.len() == count &&
+			(min_members()..=max_members()).contains(&count);
+		// the model follows the first group, the later ones are only created
+		if valid && self.members.is_empty() {
+			self.members = members;
+		}
+		valid
+	}
+
+	fn propose(&mut self, add: bool, who: u128, member: u128) -> bool {
+		let owned = self.proposals.values().filter(|proposal| proposal.owner == who).count();
+		let valid = self.members.contains(&who) &&
+			self.members.contains(&member) != add &&
+			(add || self.members.len() > min_members()) &&
+			self.proposals.len() < max_proposals().0 &&
+			owned < max_proposals().1;
+		if valid {
+			self.proposal_count += 1;
+			let proposal = ModelProposal {
+				owner: who,
+				add,
+				member,
+				ayes: vec![who],
+				nays: vec![],
+				end: self.now + 10,
+			};
+			self.proposals.insert(self.proposal_count, proposal);
+		}
+		valid
+	}
+
+	fn vote(&mut self, who: u128, proposal_id: u32, approve: bool) -> bool {
+		let members = self.members.len() as u32;
+		let threshold = self.threshold();
+		let is_member = self.members.contains(&who);
+
+		let Some(proposal) = self.proposals.get_mut(&proposal_id) else { return false };
+		if !is_member || proposal.ayes.contains(&who) || proposal.nays.contains(&who) {
+			return false
+		}
+
+		if approve {
+			proposal.ayes.push(who);
+			if proposal.ayes.len() as u32 >= threshold {
+				let proposal = self.proposals.remove(&proposal_id).expect("voted above; qed");
+				self.execute(proposal);
+			}
+		} else {
+			proposal.nays.push(who);
+			if members.saturating_sub(proposal.nays.len() as u32) < threshold {
+				self.proposals.remove(&proposal_id);
+			}
+		}
+		true
+	}
+
+	// a failed execution finishes the proposal as well
+	fn execute(&mut self, proposal: ModelProposal) {
+		match (proposal.add, self.members.binary_search(&proposal.member)) {
+			(true, Err(index)) if self.members.len() < max_members() =>
+				self.members.insert(index, proposal.member),
+			(false, Ok(index)) if self.members.len() > min_members() => {
+				self.members.remove(index);
+			},
+			_ => {},
+		}
+	}
+}
+
+fn assert_invariants(model: &Model) {
+	// membership bounds
+	let members = MultisigModule::members(0).to_vec();
+	assert!(members.len() <= max_members());
+	assert!(members.is_empty() || members.len() >= min_members());
+	assert!(members.windows(2).all(|pair| pair[0] < pair[1]), "members are sorted and unique");
+	assert_eq!(members, model.members);
+
+	// the reverse index follows the members
+	for who in 1..=7u128 {
+		assert_eq!(MultisigModule::groups_of(who).contains(&0), members.contains(&who));
+	}
+
+	let pending = crate::Proposals::<Test>::iter_keys().collect::<BTreeSet<_>>();
+	assert_eq!(pending, model.proposals.keys().copied().collect::<BTreeSet<_>>());
+	assert_eq!(MultisigModule::proposal_count(), model.proposal_count);
+
+	// no double votes
+	for (proposal_id, proposal) in &model.proposals {
+		let vote = MultisigModule::votings(proposal_id).unwrap();
+		let voters = vote.ayes.iter().chain(vote.nays.iter()).collect::<BTreeSet<_>>();
+		assert_eq!(voters.len(), vote.ayes.len() + vote.nays.len(), "double vote");
+		assert_eq!(vote.ayes, proposal.ayes);
+		assert_eq!(vote.nays, proposal.nays);
+	}
+
+	// proposals finish exactly once, when they leave the pending ones
+	let mut finished = BTreeMap::<u32, u32>::new();
+	for record in System::events() {
+		if let RuntimeEvent::MultisigModule(Event::FinishedProposal { proposal_id, .. }) =
+			record.event
+		{
+			*finished.entry(proposal_id).or_default() += 1;
+		}
+	}
+	for proposal_id in 1..=model.proposal_count {
+		let expected = u32::from(!pending.contains(&proposal_id));
+		assert_eq!(finished.get(&proposal_id).copied().unwrap_or_default(), expected);
+	}
+}
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(64))]
+
+	#[test]
+	fn voting_engine_state_machine(
+		members in prop::sample::subsequence((1..=7u128).collect::<Vec<_>>(), 3..=5),
+		actions in prop::collection::vec(action(), 1..40),
+	) {
+		new_test_ext().execute_with(|| {
+			let mut model = Model { now: System::block_number(), ..Default::default() };
+
+			let create = Action::Create { who: members[0], members: members.clone() };
+			assert!(model.apply(&create));
+			assert_ok!(dispatch(&create));
+
+			for action in actions {
+				let expected = model.apply(&action);
+				assert_eq!(dispatch(&action).is_ok(), expected, "{:?}", action);
+				assert_invariants(&model);
+			}
+
+			// the pending proposals expire once their voting period is over
+			let wait = Action::Wait { blocks: 11 };
+			model.apply(&wait);
+			assert_ok!(dispatch(&wait));
+			assert!(model.proposals.is_empty());
+			assert_invariants(&model);
+		});
+	}
+}
@@ -6,7 +6,9 @@
 #[cfg(feature = "std")]
 include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
 
+use codec::{Decode, Encode, MaxEncodedLen};
 use pallet_grandpa::AuthorityId as GrandpaId;
+use scale_info::TypeInfo;
 use sp_api::impl_runtime_apis;
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 use sp_core::{crypto::KeyTypeId, ConstBool, OpaqueMetadata};
@@ -16,7 +18,7 @@ use sp_runtime::{
 		AccountIdLookup, BlakeTwo256, Block as BlockT, IdentifyAccount, NumberFor, One, Verify,
 	},
 	transaction_validity::{TransactionSource, TransactionValidity},
-	ApplyExtrinsicResult, MultiSignature,
+	ApplyExtrinsicResult, MultiSignature, RuntimeDebug,
 };
 use sp_std::prelude::*;
 #[cfg(feature = "std")]
@@ -35,15 +37,16 @@ pub use frame_support::{
 		},
 		IdentityFee, Weight,
 	},
-	StorageValue,
+	PalletId, StorageValue,
 };
 pub use frame_system::Call as SystemCall;
+use frame_system::EnsureRoot;
 pub use pallet_balances::Call as BalancesCall;
 pub use pallet_timestamp::Call as TimestampCall;
 use pallet_transaction_payment::{ConstFeeMultiplier, CurrencyAdapter, Multiplier};
 #[cfg(any(feature = "std", test))]
 pub use sp_runtime::BuildStorage;
-pub use sp_runtime::{Perbill, Permill};
+pub use sp_runtime::{Perbill, Percent, Permill};
 
 /// Import the template pallet.
 pub use pallet_template;
@@ -107,7 +110,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	//   `spec_version`, and `authoring_version` are the same between Wasm and native.
 	// This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
 	//   the compatible custom types.
-	spec_version: 100,
+	spec_version: 101,
 	impl_version: 1,
 	apis: RUNTIME_API_VERSIONS,
 	transaction_version: 1,
@@ -234,6 +237,27 @@ impl pallet_timestamp::Config for Runtime {
 /// Existential deposit.
 pub const EXISTENTIAL_DEPOSIT: u128 = 500;
 
+/// Why the balance of an account is frozen.
+#[derive(
+	Encode,
+	Decode,
+	MaxEncodedLen,
+	TypeInfo,
+	Clone,
+	Copy,
+	PartialEq,
+	Eq,
+	PartialOrd,
+	Ord,
+	RuntimeDebug,
+)]
+pub enum FreezeReason {
+	/// Voted with in a token weighted group of the council.
+	CouncilVote,
+	/// Voted with in a token weighted group of the technical committee.
+	TechnicalVote,
+}
+
 impl pallet_balances::Config for Runtime {
 	type MaxLocks = ConstU32<50>;
 	type MaxReserves = ();
@@ -246,8 +270,8 @@ impl pallet_balances::Config for Runtime {
 	type ExistentialDeposit = ConstU128<EXISTENTIAL_DEPOSIT>;
 	type AccountStore = System;
 	type WeightInfo = pallet_balances::weights::SubstrateWeight<Runtime>;
-	type FreezeIdentifier = ();
-	type MaxFreezes = ();
+	type FreezeIdentifier = FreezeReason;
+	type MaxFreezes = ConstU32<2>;
 	type RuntimeHoldReason = ();
 	type MaxHolds = ();
 }
@@ -278,13 +302,98 @@ impl pallet_template::Config for Runtime {
 	type MaxProposal = ConstU32<5>;
 }
 
-impl pallet_smultisig::Config for Runtime {
+parameter_types! {
+	pub const PreimageBaseDeposit: Balance = 1_000 * EXISTENTIAL_DEPOSIT;
+	pub const PreimageByteDeposit: Balance = EXISTENTIAL_DEPOSIT;
+}
+
+impl pallet_preimage::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = pallet_preimage::weights::SubstrateWeight<Runtime>;
+	type Currency = Balances;
+	type ManagerOrigin = EnsureRoot<AccountId>;
+	type BaseDeposit = PreimageBaseDeposit;
+	type ByteDeposit = PreimageByteDeposit;
+}
+
+parameter_types! {
+	pub const CouncilPalletId: PalletId = PalletId(*b"py/counc");
+	pub const TechnicalPalletId: PalletId = PalletId(*b"py/techc");
+	pub const CouncilFreezeId: FreezeReason = FreezeReason::CouncilVote;
+	pub const TechnicalFreezeId: FreezeReason = FreezeReason::TechnicalVote;
+	pub const ProposalDepositBase: Balance = 100 * EXISTENTIAL_DEPOSIT;
+	pub const ProposalDepositPerByte: Balance = EXISTENTIAL_DEPOSIT;
+	pub const PetitionBond: Balance = 1_000 * EXISTENTIAL_DEPOSIT;
+	pub const PetitionForfeit: Percent = Percent::from_percent(50);
+	pub const GroupDeposit: Balance = 1_000 * EXISTENTIAL_DEPOSIT;
+	pub const FreezeThreshold: pallet_smultisig::ProposalThreshold =
+		pallet_smultisig::ProposalThreshold::MoreThanhalf;
+}
+
+type CouncilMultisig = frame_support::instances::Instance1;
+impl pallet_smultisig::Config<CouncilMultisig> for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = ();
+	type RuntimeCall = RuntimeCall;
+	type Currency = Balances;
+	type Preimages = Preimage;
+	type MembershipChanged = ();
+	type PalletId = CouncilPalletId;
+	type MaxTitleLength = ConstU32<64>;
+	type MaxGroupNameLength = ConstU32<32>;
+	type ProposalDepositBase = ProposalDepositBase;
+	type ProposalDepositPerByte = ProposalDepositPerByte;
+
+	type MaxMultisigNumber = ConstU32<7>;
+	type MaxProposalNumber = ConstU32<10>;
+	type MaxMemberProposalNumber = ConstU32<3>;
+	type MinMultisigNumber = ConstU32<3>;
+	type VotingPeriod = ConstU32<{ 7 * DAYS }>;
+	type RevealPeriod = ConstU32<{ 2 * DAYS }>;
+	type MaxMissedProposals = ConstU32<5>;
+	type InactivityPeriod = ConstU32<{ 30 * DAYS }>;
+	type FreezeThreshold = FreezeThreshold;
+	type MaxFinishedProposals = ConstU32<100>;
+	type EthVotePriority = ConstU64<{ u64::MAX / 2 }>;
+	type Freezer = Balances;
+	type FreezeId = CouncilFreezeId;
+	type PetitionBond = PetitionBond;
+	type PetitionForfeit = PetitionForfeit;
+	type GroupDeposit = GroupDeposit;
+	type MaxGroupNumber = ConstU32<32>;
+}
+
+type TechnicalMultisig = frame_support::instances::Instance2;
+impl pallet_smultisig::Config<TechnicalMultisig> for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = ();
+	type RuntimeCall = RuntimeCall;
+	type Currency = Balances;
+	type Preimages = Preimage;
+	type MembershipChanged = ();
+	type PalletId = TechnicalPalletId;
+	type MaxTitleLength = ConstU32<64>;
+	type MaxGroupNameLength = ConstU32<32>;
+	type ProposalDepositBase = ProposalDepositBase;
+	type ProposalDepositPerByte = ProposalDepositPerByte;
 
 	type MaxMultisigNumber = ConstU32<5>;
 	type MaxProposalNumber = ConstU32<10>;
-	type MinMultisigNumber = ConstU32<3>;
+	type MaxMemberProposalNumber = ConstU32<3>;
+	type MinMultisigNumber = ConstU32<2>;
+	type VotingPeriod = ConstU32<{ 3 * DAYS }>;
+	type RevealPeriod = ConstU32<{ DAYS }>;
+	type MaxMissedProposals = ConstU32<5>;
+	type InactivityPeriod = ConstU32<{ 30 * DAYS }>;
+	type FreezeThreshold = FreezeThreshold;
+	type MaxFinishedProposals = ConstU32<100>;
+	type EthVotePriority = ConstU64<{ u64::MAX / 2 }>;
+	type Freezer = Balances;
+	type FreezeId = TechnicalFreezeId;
+	type PetitionBond = PetitionBond;
+	type PetitionForfeit = PetitionForfeit;
+	type GroupDeposit = GroupDeposit;
+	type MaxGroupNumber = ConstU32<32>;
 }
 
 // Create the runtime by composing the FRAME pallets that were previously configured.
@@ -298,9 +407,11 @@ construct_runtime!(
 		Balances: pallet_balances=4,
 		TransactionPayment: pallet_transaction_payment=5,
 		Sudo: pallet_sudo=6,
+		Preimage: pallet_preimage=7,
 		// Include the custom logic from the pallet-template in the runtime.
 		TemplateModule: pallet_template=55,
-		MultisigMoudle: pallet_smultisig=56,
+		Council: pallet_smultisig::<CouncilMultisig>=56,
+		TechnicalCommittee: pallet_smultisig::<TechnicalMultisig>=57,
 	}
 );
 
@@ -327,6 +438,56 @@ pub type UncheckedExtrinsic =
 	generic::UncheckedExtrinsic<Address, RuntimeCall, Signature, SignedExtra>;
 /// The payload being signed in transactions.
 pub type SignedPayload = generic::SignedPayload<RuntimeCall, SignedExtra>;
+/// The instances of the multisig pallet, selected by their pallet index in the runtime API.
+enum MultisigInstance {
+	Council,
+	Technical,
+}
+
+impl MultisigInstance {
+	fn from_index(index: u8) -> Option<Self> {
+		use frame_support::traits::PalletInfoAccess;
+
+		match index as usize {
+			index if index == Council::index() => Some(Self::Council),
+			index if index == TechnicalCommittee::index() => Some(Self::Technical),
+			_ => None,
+		}
+	}
+}
+
+/// Moves the storage of the multisig pallet, named `MultisigMoudle` before it was instantiated,
+/// to the council instance which took its index.
+pub struct MoveMultisigToCouncil;
+
+impl frame_support::traits::OnRuntimeUpgrade for MoveMultisigToCouncil {
+	fn on_runtime_upgrade() -> Weight {
+		use frame_support::{
+			storage::KeyPrefixIterator,
+			traits::{Get, PalletInfoAccess},
+		};
+
+		const OLD_NAME: &[u8] = b"MultisigMoudle";
+
+		// nothing is left under the old prefix once it was moved
+		let prefix = sp_core::hashing::twox_128(OLD_NAME).to_vec();
+		let keys = KeyPrefixIterator::new(prefix.clone(), prefix, |_| Ok(())).count() as u64;
+		if keys > 0 {
+			frame_support::storage::migration::move_pallet(OLD_NAME, Council::name().as_bytes());
+		}
+
+		<Runtime as frame_system::Config>::DbWeight::get().reads_writes(keys + 1, keys * 2)
+	}
+}
+
+/// Migrations to apply on runtime upgrade, the storage of the council is moved before it is
+/// migrated.
+pub type Migrations = (
+	MoveMultisigToCouncil,
+	pallet_smultisig::migrations::v1::MigrateToV1<Runtime, CouncilMultisig>,
+	pallet_smultisig::migrations::v1::MigrateToV1<Runtime, TechnicalMultisig>,
+);
+
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
 	Runtime,
@@ -334,6 +495,7 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
+	Migrations,
 >;
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -348,6 +510,7 @@ mod benches {
 		[pallet_balances, Balances]
 		[pallet_timestamp, Timestamp]
 		[pallet_sudo,Sudo]
+		[pallet_preimage, Preimage]
 		[pallet_template, TemplateModule]
 	);
 }
@@ -476,6 +639,54 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_smultisig_runtime_api::SmultisigApi<Block, AccountId, Balance, BlockNumber, RuntimeEvent> for Runtime {
+		fn dry_run_proposal(
+			instance: u8,
+			proposal_id: u32,
+			accounts: Vec<AccountId>,
+		) -> Result<
+			pallet_smultisig_runtime_api::DryRunResult<AccountId, Balance, RuntimeEvent>,
+			sp_runtime::DispatchError,
+		> {
+			match MultisigInstance::from_index(instance) {
+				Some(MultisigInstance::Council) => Council::dry_run_proposal(proposal_id, accounts),
+				Some(MultisigInstance::Technical) =>
+					TechnicalCommittee::dry_run_proposal(proposal_id, accounts),
+				None => Err(sp_runtime::DispatchError::Other("unknown multisig instance")),
+			}
+		}
+
+		fn proposal_threshold(
+			instance: u8,
+			group_id: pallet_smultisig_runtime_api::GroupId,
+			proposaltype: pallet_smultisig_runtime_api::ProposalType,
+		) -> Option<pallet_smultisig_runtime_api::ProposalThreshold> {
+			match MultisigInstance::from_index(instance)? {
+				MultisigInstance::Council => Some(Council::threshold_of(group_id, proposaltype)),
+				MultisigInstance::Technical =>
+					Some(TechnicalCommittee::threshold_of(group_id, proposaltype)),
+			}
+		}
+
+		fn group_info(
+			instance: u8,
+			group_id: pallet_smultisig_runtime_api::GroupId,
+		) -> Option<pallet_smultisig_runtime_api::GroupInfo<AccountId, BlockNumber, Vec<u8>>> {
+			match MultisigInstance::from_index(instance)? {
+				MultisigInstance::Council => Council::group_info(group_id),
+				MultisigInstance::Technical => TechnicalCommittee::group_info(group_id),
+			}
+		}
+
+		fn groups_of(instance: u8, who: AccountId) -> Vec<pallet_smultisig_runtime_api::GroupId> {
+			match MultisigInstance::from_index(instance) {
+				Some(MultisigInstance::Council) => Council::groups_of(who),
+				Some(MultisigInstance::Technical) => TechnicalCommittee::groups_of(who),
+				None => Vec::new(),
+			}
+		}
+	}
+
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<Block, Balance> for Runtime {
 		fn query_info(
 			uxt: <Block as BlockT>::Extrinsic,